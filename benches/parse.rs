@@ -0,0 +1,111 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use iso_8601::*;
+use std::str::FromStr;
+
+macro_rules! bench {
+    ($name:ident, $ty:ty, [$(($input_name:expr, $input:expr)),* $(,)?]) => {
+        fn $name(c: &mut Criterion) {
+            let mut group = c.benchmark_group(stringify!($ty));
+            $(
+                group.bench_function($input_name, |b| {
+                    b.iter(|| <$ty>::from_str(black_box($input)))
+                });
+            )*
+            group.finish();
+        }
+    };
+}
+
+bench! { date, Date, [
+    ("ymd", "2018-08-02"),
+    ("ymd_basic", "20180802"),
+    ("wd", "2018-W31-4"),
+    ("o", "2018-214"),
+] }
+
+bench! { date_approx, ApproxDate, [
+    ("ymd", "2018-08-02"),
+    ("ym", "2018-08"),
+    ("y", "2018"),
+    ("c", "20"),
+    ("w", "2018-W31"),
+] }
+
+bench! { time_any_approx, ApproxAnyTime, [
+    ("hms", "13:42:02"),
+    ("hms_global", "13:42:02.5+02:00"),
+    ("hm_basic", "1342Z"),
+    ("h", "13"),
+] }
+
+bench! { datetime_global, DateTime<Date, GlobalTime>, [
+    ("rfc3339", "2018-08-02T13:42:02Z"),
+    ("rfc3339_fraction_offset", "2018-08-02T13:42:02.123456+02:00"),
+    ("basic", "20180802T134202Z"),
+    ("basic_fraction_offset", "20180802T134202.123456+0200"),
+] }
+
+bench! { datetime_local, DateTime<Date, LocalTime>, [
+    ("extended", "2018-08-02T13:42:02.5"),
+    ("basic", "20180802T134202.5"),
+] }
+
+bench! { datetime_any, DateTime<Date, AnyTime>, [
+    ("rfc3339", "2018-08-02T13:42:02Z"),
+    ("rfc3339_fraction_offset", "2018-08-02T13:42:02.123456+02:00"),
+    ("basic", "20180802T134202Z"),
+    ("basic_fraction_offset", "20180802T134202.123456+0200"),
+] }
+
+/// The fast path for RFC 3339 against the general parser it falls back to,
+/// on the same input.
+fn datetime_hms_fast_path(c: &mut Criterion) {
+    let mut group = c.benchmark_group("datetime_hms_fast_path");
+    for (name, input) in &[
+        ("rfc3339", "2018-08-02T13:42:02Z"),
+        (
+            "rfc3339_fraction_offset",
+            "2018-08-02T13:42:02.123456+02:00",
+        ),
+    ] {
+        let input = input.as_bytes();
+        group.bench_function(format!("global/{}", name), |b| {
+            b.iter(|| parsers::datetime_global_hms::<()>(black_box(input)))
+        });
+        group.bench_function(format!("global_general/{}", name), |b| {
+            b.iter(|| parsers::datetime_global_hms_general::<()>(black_box(input)))
+        });
+        group.bench_function(format!("any/{}", name), |b| {
+            b.iter(|| parsers::datetime_any_hms::<()>(black_box(input)))
+        });
+        group.bench_function(format!("any_general/{}", name), |b| {
+            b.iter(|| parsers::datetime_any_hms_general::<()>(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+bench! { datetime_approx_any_approx, DateTime<ApproxDate, ApproxAnyTime>, [
+    ("ymd_hms", "2018-08-02T13:42:02Z"),
+    ("w_hm", "2018-W31T13:42+02:00"),
+] }
+
+bench! { partial_datetime_approx_any_approx, PartialDateTime<ApproxDate, ApproxAnyTime>, [
+    ("date", "2018-08-02"),
+    ("time", "13:42:02Z"),
+    ("datetime", "2018-08-02T13:42:02Z"),
+] }
+
+criterion_group!(
+    benches,
+    date,
+    date_approx,
+    time_any_approx,
+    datetime_global,
+    datetime_local,
+    datetime_any,
+    datetime_hms_fast_path,
+    datetime_approx_any_approx,
+    partial_datetime_approx_any_approx,
+);
+criterion_main!(benches);
@@ -0,0 +1,17 @@
+#![no_main]
+
+use iso_8601::*;
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+
+fuzz_target!(|input: &str| {
+    let _ = Date::from_str(input).map(|date| date.to_string());
+    let _ = ApproxDate::from_str(input).map(|date| date.to_string());
+    let _ = YmdDate::from_str(input);
+    let _ = YmDate::from_str(input);
+    let _ = YDate::from_str(input);
+    let _ = CDate::from_str(input);
+    let _ = WdDate::from_str(input);
+    let _ = WDate::from_str(input);
+    let _ = ODate::from_str(input);
+});
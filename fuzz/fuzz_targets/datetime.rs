@@ -0,0 +1,14 @@
+#![no_main]
+
+use iso_8601::*;
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+
+fuzz_target!(|input: &str| {
+    let _ = DateTime::<Date, GlobalTime>::from_str(input).map(|dt| dt.to_string());
+    let _ = DateTime::<Date, LocalTime>::from_str(input);
+    let _ = DateTime::<Date, AnyTime>::from_str(input);
+    let _ = DateTime::<Date, ApproxAnyTime>::from_str(input);
+    let _ = DateTime::<ApproxDate, AnyTime>::from_str(input);
+    let _ = DateTime::<ApproxDate, ApproxAnyTime>::from_str(input).map(|dt| dt.to_string());
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use iso_8601::*;
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+
+fuzz_target!(|input: &str| {
+    let _ = PartialDateTime::<ApproxDate, ApproxAnyTime>::from_str(input).map(|dt| dt.to_string());
+});
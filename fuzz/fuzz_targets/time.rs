@@ -0,0 +1,18 @@
+#![no_main]
+
+use iso_8601::*;
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+
+fuzz_target!(|input: &str| {
+    let _ = HmsTime::from_str(input);
+    let _ = HmTime::from_str(input);
+    let _ = HTime::from_str(input);
+    let _ = ApproxNaiveTime::from_str(input);
+    let _ = <LocalTime>::from_str(input);
+    let _ = <GlobalTime>::from_str(input);
+    let _ = <AnyTime>::from_str(input).map(|time| time.to_string());
+    let _ = ApproxLocalTime::from_str(input);
+    let _ = ApproxGlobalTime::from_str(input);
+    let _ = ApproxAnyTime::from_str(input).map(|time| time.to_string());
+});
@@ -2,114 +2,854 @@
 
 extern crate chrono;
 
-use self::chrono::prelude::*;
-
-impl From<::DateTime<::Date, ::GlobalTime>> for DateTime<FixedOffset> {
-    fn from(dt: ::DateTime<::Date, ::GlobalTime>) -> Self {
-        let date: ::YmdDate = dt.date.into();
-
-        FixedOffset::east((dt.time.timezone * 60).into())
-            .ymd(date.year.into(), date.month.into(), date.day.into())
-            .and_hms_nano(
-                dt.time.local.naive.hour.into(),
-                dt.time.local.naive.minute.into(),
-                dt.time.local.naive.second.into(),
-                dt.time.local.nanosecond(),
-            )
+use self::chrono::{prelude::*, IsoWeek, LocalResult};
+use crate::{EndOfDay, Valid};
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+};
+
+/// Reasons a conversion between this crate and chrono can fail.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Error {
+    /// The date does not exist or is out of range.
+    InvalidDate,
+    /// The time of day does not exist.
+    InvalidTime,
+    /// The difference from UTC is out of range.
+    InvalidOffset,
+    /// The local time is skipped in the local time zone,
+    /// for example when clocks are set forward.
+    NonexistentLocalTime,
+    /// The local time occurs twice in the local time zone,
+    /// for example when clocks are set back.
+    AmbiguousLocalTime,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidDate => "invalid or out-of-range date",
+            Error::InvalidTime => "invalid time of day",
+            Error::InvalidOffset => "out-of-range difference from UTC",
+            Error::NonexistentLocalTime => "local time does not exist in the local time zone",
+            Error::AmbiguousLocalTime => "local time is ambiguous in the local time zone",
+        })
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn weekday(day: u8) -> Result<Weekday, Error> {
+    Ok(match day {
+        1 => Weekday::Mon,
+        2 => Weekday::Tue,
+        3 => Weekday::Wed,
+        4 => Weekday::Thu,
+        5 => Weekday::Fri,
+        6 => Weekday::Sat,
+        7 => Weekday::Sun,
+        _ => return Err(Error::InvalidDate),
+    })
+}
+
+fn year(year: i32) -> Result<i16, Error> {
+    i16::try_from(year).or(Err(Error::InvalidDate))
+}
+
+impl TryFrom<crate::YmdDate> for NaiveDate {
+    type Error = Error;
+
+    fn try_from(date: crate::YmdDate) -> Result<Self, Self::Error> {
+        NaiveDate::from_ymd_opt(date.year.into(), date.month.into(), date.day.into())
+            .ok_or(Error::InvalidDate)
     }
 }
 
-impl From<::DateTime<::Date, ::GlobalTime>> for DateTime<Utc> {
-    fn from(dt: ::DateTime<::Date, ::GlobalTime>) -> Self {
-        DateTime::<FixedOffset>::from(dt).with_timezone(&Utc)
+impl TryFrom<crate::WdDate> for NaiveDate {
+    type Error = Error;
+
+    fn try_from(date: crate::WdDate) -> Result<Self, Self::Error> {
+        NaiveDate::from_isoywd_opt(date.year.into(), date.week.into(), weekday(date.day)?)
+            .ok_or(Error::InvalidDate)
     }
 }
 
-impl From<::DateTime<::Date, ::GlobalTime>> for DateTime<Local> {
-    fn from(dt: ::DateTime<::Date, ::GlobalTime>) -> Self {
-        DateTime::<FixedOffset>::from(dt).with_timezone(&Local)
+impl TryFrom<crate::ODate> for NaiveDate {
+    type Error = Error;
+
+    fn try_from(date: crate::ODate) -> Result<Self, Self::Error> {
+        NaiveDate::from_yo_opt(date.year.into(), date.day.into()).ok_or(Error::InvalidDate)
     }
 }
 
-impl From<::DateTime<::Date, ::LocalTime>> for DateTime<FixedOffset> {
-    fn from(dt: ::DateTime<::Date, ::LocalTime>) -> Self {
-        DateTime::<Local>::from(dt).with_timezone(&Utc.fix())
+impl TryFrom<crate::Date> for NaiveDate {
+    type Error = Error;
+
+    fn try_from(date: crate::Date) -> Result<Self, Self::Error> {
+        match date {
+            crate::Date::YMD(date) => date.try_into(),
+            crate::Date::WD(date) => date.try_into(),
+            crate::Date::O(date) => date.try_into(),
+        }
     }
 }
 
-impl From<::DateTime<::Date, ::LocalTime>> for DateTime<Utc> {
-    fn from(dt: ::DateTime<::Date, ::LocalTime>) -> Self {
-        DateTime::<Local>::from(dt).with_timezone(&Utc)
+impl TryFrom<NaiveDate> for crate::YmdDate {
+    type Error = Error;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: year(date.year())?,
+            month: date.month() as u8,
+            day: date.day() as u8,
+        })
     }
 }
 
-impl From<::DateTime<::Date, ::LocalTime>> for DateTime<Local> {
-    fn from(dt: ::DateTime<::Date, ::LocalTime>) -> Self {
-        let date: ::YmdDate = dt.date.into();
+impl TryFrom<NaiveDate> for crate::WdDate {
+    type Error = Error;
 
-        Local
-            .from_local_datetime(
-                &NaiveDate::from_ymd(date.year.into(), date.month.into(), date.day.into())
-                    .and_hms_nano(
-                        dt.time.naive.hour.into(),
-                        dt.time.naive.minute.into(),
-                        dt.time.naive.second.into(),
-                        dt.time.nanosecond(),
-                    ),
-            )
-            .single()
-            .unwrap()
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        (date.iso_week(), date.weekday()).try_into()
     }
 }
 
-impl From<::DateTime<::Date, ::AnyTime>> for DateTime<FixedOffset> {
-    fn from(dt: ::DateTime<::Date, ::AnyTime>) -> Self {
-        DateTime::<Local>::from(dt).with_timezone(&Utc.fix())
+impl TryFrom<NaiveDate> for crate::ODate {
+    type Error = Error;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: year(date.year())?,
+            day: date.ordinal() as u16,
+        })
     }
 }
 
-impl From<::DateTime<::Date, ::AnyTime>> for DateTime<Utc> {
-    fn from(dt: ::DateTime<::Date, ::AnyTime>) -> Self {
-        DateTime::<Local>::from(dt).with_timezone(&Utc)
+/// Calendar date representation.
+impl TryFrom<NaiveDate> for crate::Date {
+    type Error = Error;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        date.try_into().map(crate::Date::YMD)
     }
 }
 
-impl From<::DateTime<::Date, ::AnyTime>> for DateTime<Local> {
-    fn from(dt: ::DateTime<::Date, ::AnyTime>) -> Self {
+impl TryFrom<crate::WDate> for IsoWeek {
+    type Error = Error;
+
+    fn try_from(date: crate::WDate) -> Result<Self, Self::Error> {
+        NaiveDate::from_isoywd_opt(date.year.into(), date.week.into(), Weekday::Mon)
+            .map(|date| date.iso_week())
+            .ok_or(Error::InvalidDate)
+    }
+}
+
+impl TryFrom<IsoWeek> for crate::WDate {
+    type Error = Error;
+
+    fn try_from(week: IsoWeek) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: year(week.year())?,
+            week: week.week() as u8,
+        })
+    }
+}
+
+impl TryFrom<crate::WdDate> for (IsoWeek, Weekday) {
+    type Error = Error;
+
+    fn try_from(date: crate::WdDate) -> Result<Self, Self::Error> {
+        let day = weekday(date.day)?;
+        Ok((crate::WDate::from(date).try_into()?, day))
+    }
+}
+
+impl TryFrom<(IsoWeek, Weekday)> for crate::WdDate {
+    type Error = Error;
+
+    fn try_from((week, day): (IsoWeek, Weekday)) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: year(week.year())?,
+            week: week.week() as u8,
+            day: day.number_from_monday() as u8,
+        })
+    }
+}
+
+/// Maps a leap second to chrono's representation
+/// of a nanosecond value of one second or more at `:59`.
+impl TryFrom<crate::LocalTime> for NaiveTime {
+    type Error = Error;
+
+    fn try_from(time: crate::LocalTime) -> Result<Self, Self::Error> {
+        if !time.is_valid() {
+            return Err(Error::InvalidTime);
+        }
+
+        let (second, nanosecond) = match time.naive.second {
+            60 => (59, 1_000_000_000 + time.nanosecond()),
+            second => (second, time.nanosecond()),
+        };
+        NaiveTime::from_hms_nano_opt(
+            time.naive.hour.into(),
+            time.naive.minute.into(),
+            second.into(),
+            nanosecond,
+        )
+        .ok_or(Error::InvalidTime)
+    }
+}
+
+impl TryFrom<crate::HmsTime> for NaiveTime {
+    type Error = Error;
+
+    fn try_from(time: crate::HmsTime) -> Result<Self, Self::Error> {
+        crate::LocalTime {
+            naive: time,
+            fraction: 0.,
+        }
+        .try_into()
+    }
+}
+
+impl From<NaiveTime> for crate::LocalTime {
+    fn from(time: NaiveTime) -> Self {
+        let (second, nanosecond) = match time.nanosecond() {
+            nanosecond if nanosecond >= 1_000_000_000 => (60, nanosecond - 1_000_000_000),
+            nanosecond => (time.second() as u8, nanosecond),
+        };
+        Self {
+            naive: crate::HmsTime {
+                hour: time.hour() as u8,
+                minute: time.minute() as u8,
+                second,
+            },
+            fraction: crate::datetime::fraction(nanosecond),
+        }
+    }
+}
+
+/// Drops fractions of a second.
+impl From<NaiveTime> for crate::HmsTime {
+    fn from(time: NaiveTime) -> Self {
+        crate::LocalTime::from(time).naive
+    }
+}
+
+impl<N> TryFrom<crate::GlobalTime<N>> for FixedOffset
+where
+    N: crate::NaiveTime,
+{
+    type Error = Error;
+
+    fn try_from(time: crate::GlobalTime<N>) -> Result<Self, Self::Error> {
+        FixedOffset::east_opt(i32::from(time.timezone) * 60).ok_or(Error::InvalidOffset)
+    }
+}
+
+impl TryFrom<crate::GlobalTime> for (NaiveTime, FixedOffset) {
+    type Error = Error;
+
+    fn try_from(time: crate::GlobalTime) -> Result<Self, Self::Error> {
+        let offset = FixedOffset::try_from(time.clone())?;
+        Ok((time.local.try_into()?, offset))
+    }
+}
+
+/// Fails if the offset is not a whole number of minutes.
+impl TryFrom<(NaiveTime, FixedOffset)> for crate::GlobalTime {
+    type Error = Error;
+
+    fn try_from((time, offset): (NaiveTime, FixedOffset)) -> Result<Self, Self::Error> {
+        let seconds = offset.local_minus_utc();
+        if seconds % 60 != 0 {
+            return Err(Error::InvalidOffset);
+        }
+        Ok(Self {
+            local: time.into(),
+            timezone: (seconds / 60) as i16,
+        })
+    }
+}
+
+/// Maps the end of a day (`24:00`) to the beginning of the next one.
+fn naive_datetime(
+    dt: crate::DateTime<crate::Date, crate::LocalTime>,
+) -> Result<NaiveDateTime, Error> {
+    let date = NaiveDate::try_from(dt.date)?;
+    Ok(match dt.time.start_of_next_day() {
+        Some(time) => date
+            .succ_opt()
+            .ok_or(Error::InvalidDate)?
+            .and_time(time.try_into()?),
+        None => date.and_time(dt.time.try_into()?),
+    })
+}
+
+fn single<Tz: TimeZone>(result: LocalResult<DateTime<Tz>>) -> Result<DateTime<Tz>, Error> {
+    match result {
+        LocalResult::Single(dt) => Ok(dt),
+        LocalResult::None => Err(Error::NonexistentLocalTime),
+        LocalResult::Ambiguous(_, _) => Err(Error::AmbiguousLocalTime),
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::GlobalTime>> for DateTime<FixedOffset> {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Result<Self, Self::Error> {
+        let offset = FixedOffset::try_from(dt.time.clone())?;
+        single(offset.from_local_datetime(&naive_datetime(crate::DateTime {
+            date: dt.date,
+            time: dt.time.local,
+        })?))
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::GlobalTime>> for DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Result<Self, Self::Error> {
+        Ok(DateTime::<FixedOffset>::try_from(dt)?.with_timezone(&Utc))
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::GlobalTime>> for DateTime<Local> {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Result<Self, Self::Error> {
+        Ok(DateTime::<FixedOffset>::try_from(dt)?.with_timezone(&Local))
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::LocalTime>> for DateTime<FixedOffset> {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::LocalTime>) -> Result<Self, Self::Error> {
+        Ok(DateTime::<Local>::try_from(dt)?.with_timezone(&Utc.fix()))
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::LocalTime>> for DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::LocalTime>) -> Result<Self, Self::Error> {
+        Ok(DateTime::<Local>::try_from(dt)?.with_timezone(&Utc))
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::LocalTime>> for DateTime<Local> {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::LocalTime>) -> Result<Self, Self::Error> {
+        single(Local.from_local_datetime(&naive_datetime(dt)?))
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::AnyTime>> for DateTime<FixedOffset> {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::AnyTime>) -> Result<Self, Self::Error> {
+        Ok(DateTime::<Local>::try_from(dt)?.with_timezone(&Utc.fix()))
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::AnyTime>> for DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::AnyTime>) -> Result<Self, Self::Error> {
+        Ok(DateTime::<Local>::try_from(dt)?.with_timezone(&Utc))
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::AnyTime>> for DateTime<Local> {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::AnyTime>) -> Result<Self, Self::Error> {
         match dt.time {
-            ::AnyTime::Global(time) => ::DateTime {
+            crate::AnyTime::Global(time) => crate::DateTime {
                 date: dt.date,
                 time,
             }
-            .into(),
-            ::AnyTime::Local(time) => ::DateTime {
+            .try_into(),
+            crate::AnyTime::Local(time) => crate::DateTime {
                 date: dt.date,
                 time,
             }
-            .into(),
+            .try_into(),
         }
     }
 }
 
+/// Date a reduced accuracy date stands for in conversions,
+/// see [`ApproxDate::first_day`](crate::ApproxDate::first_day).
+trait IntoDate {
+    fn into_date(self) -> crate::Date;
+}
+
+impl IntoDate for crate::Date {
+    fn into_date(self) -> crate::Date {
+        self
+    }
+}
+
+impl IntoDate for crate::ApproxDate {
+    fn into_date(self) -> crate::Date {
+        self.first_day()
+    }
+}
+
+macro_rules! impl_try_from_via {
+    ($date:ty, $time:ty => $via:ty) => {
+        impl_try_from_via!($date, $time => $via, FixedOffset);
+        impl_try_from_via!($date, $time => $via, Utc);
+        impl_try_from_via!($date, $time => $via, Local);
+    };
+    ($date:ty, $time:ty => $via:ty, $tz:ty) => {
+        /// Reduced accuracy dates and times stand for the beginning of their period.
+        impl TryFrom<crate::DateTime<$date, $time>> for DateTime<$tz> {
+            type Error = Error;
+
+            fn try_from(dt: crate::DateTime<$date, $time>) -> Result<Self, Self::Error> {
+                if !dt.date.is_valid() {
+                    return Err(Error::InvalidDate);
+                }
+                if !dt.time.is_valid() {
+                    return Err(Error::InvalidTime);
+                }
+
+                crate::DateTime::<crate::Date, $via> {
+                    date: dt.date.into_date(),
+                    time: dt.time.into(),
+                }
+                .try_into()
+            }
+        }
+    };
+}
+impl_try_from_via!(crate::Date,       crate::LocalTime<crate::HmTime>   => crate::LocalTime);
+impl_try_from_via!(crate::Date,       crate::LocalTime<crate::HTime>    => crate::LocalTime);
+impl_try_from_via!(crate::Date,       crate::ApproxLocalTime            => crate::LocalTime);
+impl_try_from_via!(crate::Date,       crate::GlobalTime<crate::HmTime>  => crate::GlobalTime);
+impl_try_from_via!(crate::Date,       crate::GlobalTime<crate::HTime>   => crate::GlobalTime);
+impl_try_from_via!(crate::Date,       crate::ApproxGlobalTime           => crate::GlobalTime);
+impl_try_from_via!(crate::Date,       crate::AnyTime<crate::HmTime>     => crate::AnyTime);
+impl_try_from_via!(crate::Date,       crate::AnyTime<crate::HTime>      => crate::AnyTime);
+impl_try_from_via!(crate::Date,       crate::ApproxAnyTime              => crate::AnyTime);
+impl_try_from_via!(crate::ApproxDate, crate::LocalTime                  => crate::LocalTime);
+impl_try_from_via!(crate::ApproxDate, crate::LocalTime<crate::HmTime>   => crate::LocalTime);
+impl_try_from_via!(crate::ApproxDate, crate::LocalTime<crate::HTime>    => crate::LocalTime);
+impl_try_from_via!(crate::ApproxDate, crate::ApproxLocalTime            => crate::LocalTime);
+impl_try_from_via!(crate::ApproxDate, crate::GlobalTime                 => crate::GlobalTime);
+impl_try_from_via!(crate::ApproxDate, crate::GlobalTime<crate::HmTime>  => crate::GlobalTime);
+impl_try_from_via!(crate::ApproxDate, crate::GlobalTime<crate::HTime>   => crate::GlobalTime);
+impl_try_from_via!(crate::ApproxDate, crate::ApproxGlobalTime           => crate::GlobalTime);
+impl_try_from_via!(crate::ApproxDate, crate::AnyTime                    => crate::AnyTime);
+impl_try_from_via!(crate::ApproxDate, crate::AnyTime<crate::HmTime>     => crate::AnyTime);
+impl_try_from_via!(crate::ApproxDate, crate::AnyTime<crate::HTime>      => crate::AnyTime);
+impl_try_from_via!(crate::ApproxDate, crate::ApproxAnyTime              => crate::AnyTime);
+
+macro_rules! impl_try_from_chrono {
+    ($($date:ty),*) => {
+        $(
+            /// Maps chrono's leap second representation to `:60`.
+            impl TryFrom<NaiveDateTime> for crate::DateTime<$date, crate::LocalTime> {
+                type Error = Error;
+
+                fn try_from(dt: NaiveDateTime) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        date: dt.date().try_into()?,
+                        time: dt.time().into(),
+                    })
+                }
+            }
+
+            /// Keeps the local time and offset of the chrono value.
+            /// Fails if the offset is not a whole number of minutes.
+            impl<Tz: TimeZone> TryFrom<DateTime<Tz>> for crate::DateTime<$date, crate::GlobalTime> {
+                type Error = Error;
+
+                fn try_from(dt: DateTime<Tz>) -> Result<Self, Self::Error> {
+                    let local = dt.naive_local();
+                    Ok(Self {
+                        date: local.date().try_into()?,
+                        time: (local.time(), dt.offset().fix()).try_into()?,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_chrono!(crate::Date, crate::YmdDate, crate::WdDate, crate::ODate);
+
 #[cfg(feature = "chrono-serde")]
 pub mod serde {
     extern crate serde;
 
-    use self::serde::{Deserialize, Deserializer};
+    use self::serde::Deserializer;
     use super::{DateTime, TimeZone};
+    use std::{
+        convert::{TryFrom, TryInto},
+        fmt::Display,
+    };
 
     #[allow(non_snake_case)]
     pub fn deserialize_DateTime<'de, D, Tz>(de: D) -> Result<DateTime<Tz>, D::Error>
     where
         D: Deserializer<'de>,
         Tz: TimeZone,
-        DateTime<Tz>: From<::DateTime<::ApproxDate, ::ApproxAnyTime>>,
+        DateTime<Tz>: TryFrom<crate::DateTime<crate::ApproxDate, crate::ApproxAnyTime>>,
+        <DateTime<Tz> as TryFrom<crate::DateTime<crate::ApproxDate, crate::ApproxAnyTime>>>::Error:
+            Display,
     {
-        Ok(
-            ::parse::datetime_approx_any_approx(String::deserialize(de)?.as_bytes())
-                .map_err(serde::de::Error::custom)?
-                .1
-                .into(),
+        crate::serde::extended::deserialize::<
+            crate::DateTime<crate::ApproxDate, crate::ApproxAnyTime>,
+            _,
+        >(de)?
+        .try_into()
+        .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn leap_second() {
+        let dt = DateTime::<FixedOffset>::try_from(
+            crate::DateTime::<crate::Date, crate::GlobalTime>::from_str("2016-12-31T23:59:60.5Z")
+                .unwrap(),
         )
+        .unwrap();
+        assert_eq!(dt.second(), 59);
+        assert_eq!(dt.nanosecond(), 1_500_000_000);
+        assert_eq!(
+            DateTime::<Utc>::from(dt).naive_utc(),
+            NaiveDate::from_ymd_opt(2016, 12, 31)
+                .unwrap()
+                .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn end_of_day() {
+        assert_eq!(
+            DateTime::<FixedOffset>::try_from(
+                crate::DateTime::<crate::Date, crate::GlobalTime>::from_str("2018-08-02T24:00:00Z")
+                    .unwrap(),
+            ),
+            DateTime::<FixedOffset>::try_from(
+                crate::DateTime::<crate::Date, crate::GlobalTime>::from_str("2018-08-03T00:00:00Z")
+                    .unwrap(),
+            )
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let global = |s| {
+            DateTime::<Utc>::try_from(
+                crate::DateTime::<crate::Date, crate::GlobalTime>::from_str(s).unwrap(),
+            )
+        };
+        assert_eq!(global("2018-02-30T00:00:00Z"), Err(Error::InvalidDate));
+        assert_eq!(global("2018-W60-1T00:00:00Z"), Err(Error::InvalidDate));
+        assert_eq!(global("2018-08-02T25:00:00Z"), Err(Error::InvalidTime));
+        assert_eq!(global("2018-08-02T24:00:01Z"), Err(Error::InvalidTime));
+        assert_eq!(
+            global("2018-08-02T00:00:00+24:00"),
+            Err(Error::InvalidOffset)
+        );
+
+        assert_eq!(
+            DateTime::<Utc>::try_from(crate::DateTime {
+                date: crate::Date::O(crate::ODate {
+                    year: 2018,
+                    day: 400,
+                }),
+                time: "00:00:00Z".parse::<crate::GlobalTime>().unwrap(),
+            }),
+            Err(Error::InvalidDate)
+        );
+        assert_eq!(
+            DateTime::<Local>::try_from(
+                crate::DateTime::<crate::Date, crate::AnyTime>::from_str("2018-02-30T00:00:00")
+                    .unwrap()
+            ),
+            Err(Error::InvalidDate)
+        );
+    }
+
+    #[test]
+    fn approx() {
+        let expected = DateTime::<Utc>::try_from(
+            crate::DateTime::<crate::Date, crate::GlobalTime>::from_str("2018-08-01T12:30:00Z")
+                .unwrap(),
+        );
+        assert_eq!(
+            DateTime::<Utc>::try_from(
+                crate::DateTime::<crate::ApproxDate, crate::ApproxGlobalTime>::from_str(
+                    "2018-08T12:30Z"
+                )
+                .unwrap()
+            ),
+            expected
+        );
+        assert_eq!(
+            DateTime::<Utc>::try_from(
+                crate::DateTime::<crate::ApproxDate, crate::GlobalTime<crate::HTime>>::from_str(
+                    "2018-W31T12.5Z"
+                )
+                .unwrap()
+            ),
+            DateTime::<Utc>::try_from(
+                crate::DateTime::<crate::Date, crate::GlobalTime>::from_str("2018-07-30T12:30:00Z")
+                    .unwrap(),
+            )
+        );
+        assert_eq!(
+            DateTime::<Utc>::try_from(
+                crate::DateTime::<crate::Date, crate::AnyTime<crate::HmTime>>::from_str(
+                    "2018-08-01T12:30Z"
+                )
+                .unwrap()
+            ),
+            expected
+        );
+        assert_eq!(
+            DateTime::<Utc>::try_from(
+                crate::DateTime::<crate::ApproxDate, crate::ApproxAnyTime>::from_str(
+                    "2018-08T12:60Z"
+                )
+                .unwrap()
+            ),
+            Err(Error::InvalidTime)
+        );
+    }
+
+    #[cfg(feature = "chrono-serde")]
+    #[test]
+    fn deserialize_datetime() {
+        use ::serde::de::{value, IntoDeserializer};
+
+        let de: value::StrDeserializer<value::Error> = "2018-08T12:30Z".into_deserializer();
+        assert_eq!(
+            super::serde::deserialize_DateTime::<_, Utc>(de),
+            Ok(DateTime::<Utc>::try_from(
+                crate::DateTime::<crate::Date, crate::GlobalTime>::from_str("2018-08-01T12:30:00Z")
+                    .unwrap()
+            )
+            .unwrap())
+        );
+    }
+
+    #[test]
+    fn naive_date() {
+        let expected = NaiveDate::from_ymd_opt(2018, 8, 2).unwrap();
+        for date in &["2018-08-02", "2018-W31-4", "2018-214"] {
+            let date = crate::Date::from_str(date).unwrap();
+            assert_eq!(NaiveDate::try_from(date), Ok(expected));
+        }
+
+        assert_eq!(
+            crate::YmdDate::try_from(expected),
+            Ok(crate::YmdDate {
+                year: 2018,
+                month: 8,
+                day: 2,
+            })
+        );
+        assert_eq!(
+            crate::WdDate::try_from(NaiveDate::from_ymd_opt(2021, 1, 3).unwrap()),
+            Ok(crate::WdDate {
+                year: 2020,
+                week: 53,
+                day: 7,
+            })
+        );
+        assert_eq!(
+            crate::ODate::try_from(expected),
+            Ok(crate::ODate {
+                year: 2018,
+                day: 214,
+            })
+        );
+        assert_eq!(
+            crate::YmdDate::try_from(NaiveDate::from_ymd_opt(40_000, 1, 1).unwrap()),
+            Err(Error::InvalidDate)
+        );
+        assert_eq!(
+            NaiveDate::try_from(crate::WdDate {
+                year: 2018,
+                week: 1,
+                day: 8,
+            }),
+            Err(Error::InvalidDate)
+        );
+    }
+
+    #[test]
+    fn iso_week() {
+        let week = NaiveDate::from_ymd_opt(2018, 8, 2).unwrap().iso_week();
+        assert_eq!(
+            IsoWeek::try_from(crate::WDate {
+                year: 2018,
+                week: 31,
+            }),
+            Ok(week)
+        );
+        assert_eq!(
+            crate::WDate::try_from(week),
+            Ok(crate::WDate {
+                year: 2018,
+                week: 31,
+            })
+        );
+
+        let date = crate::WdDate {
+            year: 2018,
+            week: 31,
+            day: 4,
+        };
+        assert_eq!(
+            <(IsoWeek, Weekday)>::try_from(date.clone()),
+            Ok((week, Weekday::Thu))
+        );
+        assert_eq!(crate::WdDate::try_from((week, Weekday::Thu)), Ok(date));
+    }
+
+    #[test]
+    fn naive_time() {
+        let time = crate::LocalTime::from_str("13:42:02.5").unwrap();
+        let expected = NaiveTime::from_hms_milli_opt(13, 42, 2, 500).unwrap();
+        assert_eq!(NaiveTime::try_from(time.clone()), Ok(expected));
+        assert_eq!(crate::LocalTime::from(expected), time);
+        assert_eq!(
+            crate::HmsTime::from(expected),
+            crate::HmsTime {
+                hour: 13,
+                minute: 42,
+                second: 2,
+            }
+        );
+
+        let leap = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+        let time = crate::LocalTime::from_str("23:59:60.5").unwrap();
+        assert_eq!(NaiveTime::try_from(time.clone()), Ok(leap));
+        assert_eq!(crate::LocalTime::from(leap), time);
+
+        let end = NaiveTime::from_hms_nano_opt(13, 42, 2, 999_999_999).unwrap();
+        let time = crate::LocalTime::from(end);
+        assert!(time.is_valid());
+        // An `f32` only keeps about seven digits of the fraction.
+        let back = NaiveTime::try_from(time).unwrap();
+        assert_eq!(back.with_nanosecond(0), end.with_nanosecond(0));
+        assert!(back.nanosecond() > 999_999_900);
+
+        assert_eq!(
+            NaiveTime::try_from(crate::HmsTime {
+                hour: 24,
+                minute: 0,
+                second: 0,
+            }),
+            Err(Error::InvalidTime)
+        );
+    }
+
+    #[test]
+    fn fixed_offset() {
+        let time = crate::GlobalTime::from_str("13:42:02+05:30").unwrap();
+        let offset = FixedOffset::east_opt((5 * 60 + 30) * 60).unwrap();
+        let naive = NaiveTime::from_hms_opt(13, 42, 2).unwrap();
+        assert_eq!(FixedOffset::try_from(time.clone()), Ok(offset));
+        assert_eq!(
+            <(NaiveTime, FixedOffset)>::try_from(time.clone()),
+            Ok((naive, offset))
+        );
+        assert_eq!(crate::GlobalTime::try_from((naive, offset)), Ok(time));
+        assert_eq!(
+            crate::GlobalTime::try_from((naive, FixedOffset::east_opt(30).unwrap())),
+            Err(Error::InvalidOffset)
+        );
+    }
+
+    #[test]
+    fn from_chrono() {
+        let naive = NaiveDate::from_ymd_opt(2018, 8, 2)
+            .unwrap()
+            .and_hms_milli_opt(13, 42, 2, 500)
+            .unwrap();
+        let local = crate::LocalTime::from_str("13:42:02.5").unwrap();
+        assert_eq!(
+            crate::DateTime::<crate::Date, crate::LocalTime>::try_from(naive),
+            Ok(crate::DateTime::from_str("2018-08-02T13:42:02.5").unwrap())
+        );
+        assert_eq!(
+            crate::DateTime::<crate::WdDate, crate::LocalTime>::try_from(naive),
+            Ok(crate::DateTime {
+                date: crate::WdDate {
+                    year: 2018,
+                    week: 31,
+                    day: 4,
+                },
+                time: local.clone(),
+            })
+        );
+        assert_eq!(
+            crate::DateTime::<crate::ODate, crate::LocalTime>::try_from(naive),
+            Ok(crate::DateTime {
+                date: crate::ODate {
+                    year: 2018,
+                    day: 214,
+                },
+                time: local.clone(),
+            })
+        );
+
+        let offset = FixedOffset::east_opt(2 * 60 * 60).unwrap();
+        let dt = offset.from_local_datetime(&naive).unwrap();
+        assert_eq!(
+            crate::DateTime::<crate::YmdDate, crate::GlobalTime>::try_from(dt),
+            Ok(crate::DateTime {
+                date: crate::YmdDate {
+                    year: 2018,
+                    month: 8,
+                    day: 2,
+                },
+                time: crate::GlobalTime {
+                    local,
+                    timezone: 2 * 60,
+                },
+            })
+        );
+        assert_eq!(
+            crate::DateTime::<crate::Date, crate::GlobalTime>::try_from(dt.with_timezone(&Utc)),
+            Ok(crate::DateTime::from_str("2018-08-02T11:42:02.5Z").unwrap())
+        );
+        assert_eq!(
+            crate::DateTime::<crate::Date, crate::GlobalTime>::try_from(
+                FixedOffset::east_opt(30)
+                    .unwrap()
+                    .from_local_datetime(&naive)
+                    .unwrap()
+            ),
+            Err(Error::InvalidOffset)
+        );
+
+        let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+            .unwrap();
+        assert_eq!(
+            crate::DateTime::<crate::Date, crate::GlobalTime>::try_from(
+                Utc.from_utc_datetime(&leap)
+            ),
+            Ok(crate::DateTime::from_str("2016-12-31T23:59:60.5Z").unwrap())
+        );
     }
 }
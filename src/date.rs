@@ -1,5 +1,5 @@
 use crate::Valid;
-use std::convert::From;
+use core::{convert::From, fmt};
 
 /// Complete date representations
 #[derive(Eq, PartialEq, Clone, Debug)]
@@ -196,6 +196,109 @@ where
     }
 }
 
+/// Four digits, or more with a sign where the year
+/// needs an expanded representation (4.1.2.4).
+fn fmt_year(year: i16, f: &mut fmt::Formatter) -> fmt::Result {
+    if (0..=9999).contains(&year) {
+        write!(f, "{:04}", year)
+    } else {
+        write!(f, "{:+05}", year)
+    }
+}
+
+/// The basic format, without separators (2.3.3).
+fn separator(f: &fmt::Formatter) -> &'static str {
+    if f.alternate() {
+        ""
+    } else {
+        "-"
+    }
+}
+
+/// Writes the extended format, or the basic format
+/// with the alternate flag (`{:#}`).
+///
+/// Years after 9999 or before -9999 are written in the expanded
+/// representation, like `+10000-01-01`, which the parsers do not read
+/// since its number of digits has to be agreed on (4.1.2.4).
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Date::YMD(date) => date.fmt(f),
+            Date::WD(date) => date.fmt(f),
+            Date::O(date) => date.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for ApproxDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxDate::YMD(date) => date.fmt(f),
+            ApproxDate::YM(date) => date.fmt(f),
+            ApproxDate::Y(date) => date.fmt(f),
+            ApproxDate::C(date) => date.fmt(f),
+            ApproxDate::WD(date) => date.fmt(f),
+            ApproxDate::W(date) => date.fmt(f),
+            ApproxDate::O(date) => date.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for YmdDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_year(self.year, f)?;
+        let sep = separator(f);
+        write!(f, "{}{:02}{}{:02}", sep, self.month, sep, self.day)
+    }
+}
+
+/// Keeps the separator in basic format (4.1.2.3a).
+impl fmt::Display for YmDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_year(self.year, f)?;
+        write!(f, "-{:02}", self.month)
+    }
+}
+
+impl fmt::Display for YDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_year(self.year, f)
+    }
+}
+
+impl fmt::Display for CDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.century >= 0 {
+            write!(f, "{:02}", self.century)
+        } else {
+            write!(f, "{:03}", self.century)
+        }
+    }
+}
+
+impl fmt::Display for WdDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_year(self.year, f)?;
+        let sep = separator(f);
+        write!(f, "{}W{:02}{}{}", sep, self.week, sep, self.day)
+    }
+}
+
+impl fmt::Display for WDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_year(self.year, f)?;
+        write!(f, "{}W{:02}", separator(f), self.week)
+    }
+}
+
+impl fmt::Display for ODate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_year(self.year, f)?;
+        write!(f, "{}{:03}", separator(f), self.day)
+    }
+}
+
 pub trait Year {
     fn is_leap(&self) -> bool;
     fn num_weeks(&self) -> u8;
@@ -449,18 +552,28 @@ macro_rules! impl_wd_from_o {
     ($ty:ty) => {
         impl From<ODate<$ty>> for WdDate<$ty> {
             fn from(date: ODate<$ty>) -> Self {
-                // https://en.wikipedia.org/wiki/ISO_week_date#Calculating_the_week_number_of_a_given_date
-                let y = date.year % 100 % 28;
-                let cc = (date.year / 100) % 4;
-                let mut c = ((y + (y - 1) / 4 + 5 * cc - 1) % 7) as i16;
-                if c > 3 {
-                    c -= 7;
-                }
-                let dc = date.day as i16 + c;
-                Self {
-                    year: date.year,
-                    week: (dc as f32 / 7.).ceil() as u8,
-                    day: (dc % 7) as u8,
+                // https://en.wikipedia.org/wiki/ISO_week_date#Calculating_the_week_number_from_an_ordinal_date
+                let day = weekday(days_to_year(date.year as i64) + date.day as i64 - 1);
+                let week = (date.day as i32 - day as i32 + 10) / 7;
+                if week < 1 {
+                    let year = date.year - 1;
+                    Self {
+                        year,
+                        week: year.num_weeks(),
+                        day,
+                    }
+                } else if week > date.year.num_weeks().into() {
+                    Self {
+                        year: date.year + 1,
+                        week: 1,
+                        day,
+                    }
+                } else {
+                    Self {
+                        year: date.year,
+                        week: week as u8,
+                        day,
+                    }
                 }
             }
         }
@@ -509,28 +622,13 @@ macro_rules! impl_o_from_wd {
     ($ty:ty) => {
         impl From<WdDate<$ty>> for ODate<$ty> {
             fn from(date: WdDate<$ty>) -> Self {
-                // https://en.wikipedia.org/wiki/ISO_week_date#Calculating_a_date_given_the_year,_week_number_and_weekday
-
-                fn weekday_jan4(year: $ty) -> u8 {
-                    fn weekday_jan1(year: $ty) -> u8 {
-                        // https://en.wikipedia.org/wiki/Determination_of_the_day_of_the_week#Gauss's_algorithm
-                        let y = year - 1;
-                        ((1 + 5 * (y % 4) + 4 * (y % 100) + 6 * (y % 400)) % 7) as u8
-                    }
-
-                    (weekday_jan1(year) + 3) % 7
-                }
-
-                let mut day = (date.week * 7 + date.day - (weekday_jan4(date.year) + 3)) as u16;
-                if day < 1 {
-                    day += (date.year - 1).num_days();
-                }
-                if day > date.year.num_days() {
-                    day -= date.year.num_days();
-                }
-
+                // https://en.wikipedia.org/wiki/ISO_week_date#Calculating_an_ordinal_or_month_date_from_a_week_date
+                let jan4 = days_to_year(date.year as i64) + 3;
+                let (year, day) = ordinal_from_days(
+                    jan4 - weekday(jan4) as i64 + (date.week as i64 - 1) * 7 + date.day as i64,
+                );
                 Self {
-                    year: date.year,
+                    year: year as $ty,
                     day,
                 }
             }
@@ -539,9 +637,191 @@ macro_rules! impl_o_from_wd {
 }
 impl_years!(impl_o_from_wd);
 
+/// Days from 1970-01-01 to January 1st of the given year
+/// in the proleptic Gregorian calendar.
+fn days_to_year(year: i64) -> i64 {
+    let y = year - 1;
+    365 * (year - 1970) + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400) - 477
+}
+
+/// Year and day of year of the given day since 1970-01-01.
+fn ordinal_from_days(days: i64) -> (i64, u16) {
+    let mut year = 1970 + (days * 400).div_euclid(146_097);
+    while days_to_year(year) > days {
+        year -= 1;
+    }
+    while days_to_year(year + 1) <= days {
+        year += 1;
+    }
+    (year, (days - days_to_year(year) + 1) as u16)
+}
+
+/// ISO weekday (1 = Monday, 7 = Sunday) of the given day since 1970-01-01.
+fn weekday(days: i64) -> u8 {
+    // 1970-01-01 was a Thursday.
+    ((days + 3).rem_euclid(7) + 1) as u8
+}
+
+impl ODate {
+    /// Number of days since 1970-01-01, negative for earlier dates.
+    pub fn days_since_epoch(&self) -> i64 {
+        days_to_year(self.year.into()) + i64::from(self.day) - 1
+    }
+
+    /// Inverse of [`ODate::days_since_epoch`].
+    ///
+    /// Panics if the year is out of range, see [`ODate::checked_from_days_since_epoch`].
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        Self::checked_from_days_since_epoch(days).expect("year out of range")
+    }
+
+    /// Inverse of [`ODate::days_since_epoch`],
+    /// or `None` if the year is not within -9999 to 9999,
+    /// the years that can be written without expansion.
+    pub fn checked_from_days_since_epoch(days: i64) -> Option<Self> {
+        if days < days_to_year(-9999) || days >= days_to_year(10_000) {
+            return None;
+        }
+
+        let (year, day) = ordinal_from_days(days);
+        Some(Self {
+            year: year as i16,
+            day,
+        })
+    }
+
+    /// Returns the date the given number of days later
+    /// (or earlier, if negative).
+    ///
+    /// Panics if the date is invalid or the result out of range,
+    /// see [`ODate::checked_add_days`].
+    pub fn add_days(&self, days: i64) -> Self {
+        self.checked_add_days(days).expect("date out of range")
+    }
+
+    /// Returns the date the given number of days later
+    /// (or earlier, if negative), or `None` if the date is invalid
+    /// or the year of the result is not within -9999 to 9999.
+    pub fn checked_add_days(&self, days: i64) -> Option<Self> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        Self::checked_from_days_since_epoch(self.days_since_epoch().checked_add(days)?)
+    }
+}
+
+impl YmdDate {
+    /// Returns the date the given number of days later
+    /// (or earlier, if negative).
+    ///
+    /// Panics if the date is invalid or the result out of range,
+    /// see [`YmdDate::checked_add_days`].
+    pub fn add_days(&self, days: i64) -> Self {
+        self.checked_add_days(days).expect("date out of range")
+    }
+
+    /// Returns the date the given number of days later
+    /// (or earlier, if negative), or `None` if the date is invalid
+    /// or the year of the result is not within -9999 to 9999.
+    pub fn checked_add_days(&self, days: i64) -> Option<Self> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        ODate::from(self.clone())
+            .checked_add_days(days)
+            .map(Into::into)
+    }
+}
+
+impl WdDate {
+    /// Returns the date the given number of days later
+    /// (or earlier, if negative).
+    ///
+    /// Panics if the date is invalid or the result out of range,
+    /// see [`WdDate::checked_add_days`].
+    pub fn add_days(&self, days: i64) -> Self {
+        self.checked_add_days(days).expect("date out of range")
+    }
+
+    /// Returns the date the given number of days later
+    /// (or earlier, if negative), or `None` if the date is invalid
+    /// or the year of the result is not within -9999 to 9999.
+    pub fn checked_add_days(&self, days: i64) -> Option<Self> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        ODate::from(self.clone())
+            .checked_add_days(days)
+            .map(Into::into)
+    }
+}
+
+impl ApproxDate {
+    /// The first day of the period this date denotes,
+    /// such as January 1st for a year or Monday for a week.
+    pub fn first_day(&self) -> Date {
+        match self {
+            ApproxDate::YMD(date) => Date::YMD(date.clone()),
+            ApproxDate::YM(date) => Date::YMD(YmdDate {
+                year: date.year,
+                month: date.month,
+                day: 1,
+            }),
+            ApproxDate::Y(date) => Date::YMD(YmdDate {
+                year: date.year,
+                month: 1,
+                day: 1,
+            }),
+            ApproxDate::C(date) => Date::YMD(YmdDate {
+                year: i16::from(date.century) * 100,
+                month: 1,
+                day: 1,
+            }),
+            ApproxDate::WD(date) => Date::WD(date.clone()),
+            ApproxDate::W(date) => Date::WD(WdDate {
+                year: date.year,
+                week: date.week,
+                day: 1,
+            }),
+            ApproxDate::O(date) => Date::O(date.clone()),
+        }
+    }
+}
+
+impl Date {
+    /// Returns the date the given number of days later
+    /// (or earlier, if negative) in the same representation.
+    ///
+    /// Panics if the date is invalid or the result out of range,
+    /// see [`Date::checked_add_days`].
+    pub fn add_days(&self, days: i64) -> Self {
+        self.checked_add_days(days).expect("date out of range")
+    }
+
+    /// Returns the date the given number of days later
+    /// (or earlier, if negative) in the same representation,
+    /// or `None` if the date is invalid
+    /// or the year of the result is not within -9999 to 9999.
+    pub fn checked_add_days(&self, days: i64) -> Option<Self> {
+        if days == 0 && self.is_valid() {
+            return Some(self.clone());
+        }
+
+        Some(match self {
+            Date::YMD(date) => Date::YMD(date.checked_add_days(days)?),
+            Date::WD(date) => Date::WD(date.checked_add_days(days)?),
+            Date::O(date) => Date::O(date.checked_add_days(days)?),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
 
     #[test]
     fn ymd_from_wd() {
@@ -647,6 +927,227 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wd_from_o_year_boundary() {
+        assert_eq!(
+            WdDate::from(ODate { year: 2021, day: 3 }),
+            WdDate {
+                year: 2020,
+                week: 53,
+                day: 7
+            }
+        );
+        assert_eq!(
+            WdDate::from(ODate {
+                year: 2019,
+                day: 365
+            }),
+            WdDate {
+                year: 2020,
+                week: 1,
+                day: 2
+            }
+        );
+    }
+
+    #[test]
+    fn o_from_wd_year_boundary() {
+        assert_eq!(
+            ODate::from(WdDate {
+                year: 2020,
+                week: 53,
+                day: 7
+            }),
+            ODate { year: 2021, day: 3 }
+        );
+        assert_eq!(
+            ODate::from(WdDate {
+                year: 2020,
+                week: 1,
+                day: 1
+            }),
+            ODate {
+                year: 2019,
+                day: 364
+            }
+        );
+    }
+
+    #[test]
+    fn days_since_epoch() {
+        assert_eq!(ODate { year: 1970, day: 1 }.days_since_epoch(), 0);
+        assert_eq!(
+            ODate {
+                year: 2018,
+                day: 214
+            }
+            .days_since_epoch(),
+            17_745
+        );
+        assert_eq!(
+            ODate {
+                year: 1969,
+                day: 365
+            }
+            .days_since_epoch(),
+            -1
+        );
+        for days in -700_000..700_000 {
+            assert_eq!(ODate::from_days_since_epoch(days).days_since_epoch(), days);
+        }
+    }
+
+    #[test]
+    fn add_days() {
+        assert_eq!(
+            Date::YMD(YmdDate {
+                year: 2016,
+                month: 2,
+                day: 28
+            })
+            .add_days(1),
+            Date::YMD(YmdDate {
+                year: 2016,
+                month: 2,
+                day: 29
+            })
+        );
+        assert_eq!(
+            Date::YMD(YmdDate {
+                year: 2017,
+                month: 1,
+                day: 1
+            })
+            .add_days(-1),
+            Date::YMD(YmdDate {
+                year: 2016,
+                month: 12,
+                day: 31
+            })
+        );
+        assert_eq!(
+            Date::O(ODate {
+                year: 2016,
+                day: 366
+            })
+            .add_days(1),
+            Date::O(ODate { year: 2017, day: 1 })
+        );
+        assert_eq!(
+            Date::WD(WdDate {
+                year: 2015,
+                week: 53,
+                day: 7
+            })
+            .add_days(1),
+            Date::WD(WdDate {
+                year: 2016,
+                week: 1,
+                day: 1
+            })
+        );
+    }
+
+    #[test]
+    fn checked_add_days() {
+        let last = YmdDate {
+            year: 9999,
+            month: 12,
+            day: 31,
+        };
+        assert_eq!(last.checked_add_days(1), None);
+        assert_eq!(
+            last.checked_add_days(-365),
+            Some(YmdDate {
+                year: 9998,
+                month: 12,
+                day: 31
+            })
+        );
+        assert_eq!(
+            ODate {
+                year: -9999,
+                day: 1
+            }
+            .checked_add_days(-1),
+            None
+        );
+        assert_eq!(
+            ODate { year: 2018, day: 1 }.checked_add_days(i64::MAX),
+            None
+        );
+        assert_eq!(
+            Date::YMD(YmdDate {
+                year: 2018,
+                month: 13,
+                day: 1
+            })
+            .checked_add_days(1),
+            None
+        );
+        assert_eq!(
+            Date::WD(WdDate {
+                year: 2018,
+                week: 31,
+                day: 8
+            })
+            .checked_add_days(0),
+            None
+        );
+        assert_eq!(ODate::checked_from_days_since_epoch(i64::MIN), None);
+        assert_eq!(
+            ODate::checked_from_days_since_epoch(-1),
+            Some(ODate {
+                year: 1969,
+                day: 365
+            })
+        );
+    }
+
+    #[test]
+    fn first_day() {
+        assert_eq!(
+            ApproxDate::YM(YmDate {
+                year: 2018,
+                month: 8
+            })
+            .first_day(),
+            Date::YMD(YmdDate {
+                year: 2018,
+                month: 8,
+                day: 1
+            })
+        );
+        assert_eq!(
+            ApproxDate::Y(YDate { year: 2018 }).first_day(),
+            Date::YMD(YmdDate {
+                year: 2018,
+                month: 1,
+                day: 1
+            })
+        );
+        assert_eq!(
+            ApproxDate::C(CDate { century: 20 }).first_day(),
+            Date::YMD(YmdDate {
+                year: 2000,
+                month: 1,
+                day: 1
+            })
+        );
+        assert_eq!(
+            ApproxDate::W(WDate {
+                year: 2018,
+                week: 31
+            })
+            .first_day(),
+            Date::WD(WdDate {
+                year: 2018,
+                week: 31,
+                day: 1
+            })
+        );
+    }
+
     #[test]
     fn valid_date_ymd() {
         assert!(!YmdDate {
@@ -712,4 +1213,40 @@ mod tests {
         }
         .is_valid());
     }
+
+    #[test]
+    fn display() {
+        for date in &["2018-08-02", "2018-W31-4", "2018-214", "-0044-03-15"] {
+            assert_eq!(Date::from_str(date).unwrap().to_string(), *date);
+        }
+        for date in &["2018-08", "2018", "20", "2018-W31"] {
+            assert_eq!(ApproxDate::from_str(date).unwrap().to_string(), *date);
+        }
+        assert_eq!(
+            YmdDate {
+                year: 10_000,
+                month: 1,
+                day: 1,
+            }
+            .to_string(),
+            "+10000-01-01"
+        );
+        assert_eq!(CDate { century: -5 }.to_string(), "-05");
+
+        for (extended, basic) in &[
+            ("2018-08-02", "20180802"),
+            ("2018-W31-4", "2018W314"),
+            ("2018-214", "2018214"),
+        ] {
+            assert_eq!(format!("{:#}", Date::from_str(extended).unwrap()), *basic);
+        }
+        assert_eq!(
+            format!("{:#}", ApproxDate::from_str("2018-W31").unwrap()),
+            "2018W31"
+        );
+        assert_eq!(
+            format!("{:#}", ApproxDate::from_str("2018-08").unwrap()),
+            "2018-08"
+        );
+    }
 }
@@ -1,4 +1,10 @@
 use crate::{date::*, time::*, Valid};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{
+    convert::TryFrom,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub struct DateTime<D = YmdDate, T = GlobalTime>
@@ -35,6 +41,20 @@ impl_fromstr_parse!(DateTime<ApproxDate, ApproxGlobalTime>,    datetime_approx_g
 impl_fromstr_parse!(DateTime<ApproxDate, ApproxLocalTime>,     datetime_approx_local_approx);
 impl_fromstr_parse!(DateTime<ApproxDate, ApproxAnyTime>,       datetime_approx_any_approx);
 
+impl<D, T> fmt::Display for DateTime<D, T>
+where
+    D: Datelike + fmt::Display,
+    T: Timelike + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{:#}T{:#}", self.date, self.time)
+        } else {
+            write!(f, "{}T{}", self.date, self.time)
+        }
+    }
+}
+
 impl<D, T> Valid for DateTime<D, T>
 where
     D: Datelike + Valid,
@@ -45,6 +65,241 @@ where
     }
 }
 
+macro_rules! impl_normalize {
+    ($date:ty) => {
+        impl<T> DateTime<$date, T>
+        where
+            T: Timelike + EndOfDay + Clone,
+        {
+            /// Expresses the end of a day (`24:00`)
+            /// as the beginning (`00:00`) of the following day.
+            ///
+            /// Left as it is if the date is invalid
+            /// or the following day is out of range.
+            pub fn normalize(&self) -> Self {
+                match (self.time.start_of_next_day(), self.date.checked_add_days(1)) {
+                    (Some(time), Some(date)) => Self { date, time },
+                    _ => self.clone(),
+                }
+            }
+        }
+    };
+}
+impl_normalize!(Date);
+impl_normalize!(YmdDate);
+impl_normalize!(WdDate);
+impl_normalize!(ODate);
+
+impl DateTime<Date, GlobalTime> {
+    /// Like [`Valid::is_valid`], but only accepts a leap second
+    /// at `23:59:60` UTC on a day in [`LeapSeconds::current`](crate::LeapSeconds::current).
+    #[cfg(feature = "leap-seconds")]
+    pub fn is_valid_strict(&self) -> bool {
+        if !self.is_valid() {
+            return false;
+        }
+        if self.time.local.naive.second != 60 {
+            return true;
+        }
+
+        let utc = match self.checked_to_utc() {
+            Some(utc) => utc,
+            None => return false,
+        };
+        utc.time.local.naive.hour == 23
+            && utc.time.local.naive.minute == 59
+            && crate::LeapSeconds::current().contains(&utc.date)
+    }
+
+    /// Expresses the same instant with a different difference from UTC in minutes.
+    ///
+    /// The date is carried into the following or preceding day as needed
+    /// and keeps its representation.
+    ///
+    /// Panics if the date is invalid or the result out of range,
+    /// see [`DateTime::checked_with_offset`].
+    pub fn with_offset(&self, timezone: i16) -> Self {
+        self.checked_with_offset(timezone)
+            .expect("date out of range")
+    }
+
+    /// Like [`DateTime::with_offset`], but returns `None` if the date is invalid
+    /// or the year of the result is not within -9999 to 9999.
+    pub fn checked_with_offset(&self, timezone: i16) -> Option<Self> {
+        let naive = &self.time.local.naive;
+        let minutes = i64::from(naive.hour) * 60 + i64::from(naive.minute)
+            - i64::from(self.time.timezone)
+            + i64::from(timezone);
+
+        Some(Self {
+            date: self.date.checked_add_days(minutes.div_euclid(24 * 60))?,
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime {
+                        hour: (minutes.rem_euclid(24 * 60) / 60) as u8,
+                        minute: minutes.rem_euclid(60) as u8,
+                        second: naive.second,
+                    },
+                    fraction: self.time.local.fraction,
+                },
+                timezone,
+            },
+        })
+    }
+
+    /// Expresses the same instant in UTC.
+    ///
+    /// Panics if the date is invalid or the result out of range,
+    /// see [`DateTime::checked_to_utc`].
+    pub fn to_utc(&self) -> Self {
+        self.with_offset(0)
+    }
+
+    /// Like [`DateTime::to_utc`], but returns `None` if the date is invalid
+    /// or the year of the result is not within -9999 to 9999.
+    pub fn checked_to_utc(&self) -> Option<Self> {
+        self.checked_with_offset(0)
+    }
+
+    /// Seconds since 1970-01-01T00:00:00Z and the nanoseconds within that second,
+    /// or `None` if the date is invalid.
+    ///
+    /// Leap seconds are not counted, so `23:59:60` yields
+    /// the same seconds as `00:00:00` of the following day.
+    ///
+    /// The fraction of a second is an `f32`, which is accurate
+    /// to about 60 nanoseconds, so `.123456789` yields `123_456_791`.
+    pub fn to_unix_timestamp(&self) -> Option<(i64, u32)> {
+        if !self.date.is_valid() {
+            return None;
+        }
+
+        let naive = &self.time.local.naive;
+        let days = ODate::from(self.date.clone()).days_since_epoch();
+        Some((
+            days * 24 * 60 * 60
+                + i64::from(naive.hour) * 60 * 60
+                + i64::from(naive.minute) * 60
+                + i64::from(naive.second)
+                - i64::from(self.time.timezone) * 60,
+            nanosecond(self.time.local.fraction),
+        ))
+    }
+
+    /// Inverse of [`DateTime::to_unix_timestamp`],
+    /// expressed as a calendar date with the given difference from UTC in minutes.
+    ///
+    /// `None` if `nanos` is a second or more
+    /// or the year is not within -9999 to 9999.
+    /// Nanoseconds are kept to the precision of the `f32` fraction,
+    /// and never rounded up to a whole second.
+    pub fn from_unix_timestamp(secs: i64, nanos: u32, timezone: i16) -> Option<Self> {
+        if nanos >= 1_000_000_000 {
+            return None;
+        }
+
+        let secs = secs.checked_add(i64::from(timezone) * 60)?;
+        let date = ODate::checked_from_days_since_epoch(secs.div_euclid(24 * 60 * 60))?;
+        let secs = secs.rem_euclid(24 * 60 * 60);
+
+        Some(Self {
+            date: Date::YMD(date.into()),
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime {
+                        hour: (secs / (60 * 60)) as u8,
+                        minute: (secs / 60 % 60) as u8,
+                        second: (secs % 60) as u8,
+                    },
+                    fraction: fraction(nanos),
+                },
+                timezone,
+            },
+        })
+    }
+}
+
+/// The fraction of a second of the given nanoseconds,
+/// rounded to the nearest `f32` below one.
+pub(crate) fn fraction(nanos: u32) -> f32 {
+    // The largest `f32` below one.
+    const MAX: f32 = 1. - f32::EPSILON / 2.;
+
+    ((f64::from(nanos) / 1_000_000_000.) as f32).min(MAX)
+}
+
+/// The nanoseconds of a fraction of a second, rounded to the nearest
+/// and kept below one second.
+fn nanosecond(fraction: f32) -> u32 {
+    (f64::from(fraction) * 1_000_000_000.)
+        .round()
+        .min(999_999_999.) as u32
+}
+
+/// Why a conversion between a global datetime and [`SystemTime`] fails.
+#[cfg(feature = "std")]
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum TimestampError {
+    /// The date does not exist, like `2018-13-01`.
+    InvalidDate,
+    /// The instant is out of the range of the other type,
+    /// like a year after 9999 or a `SystemTime` the platform cannot hold.
+    OutOfRange,
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for TimestampError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TimestampError::InvalidDate => "invalid date",
+            TimestampError::OutOfRange => "instant out of range",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TimestampError {}
+
+#[cfg(feature = "std")]
+impl TryFrom<SystemTime> for DateTime<Date, GlobalTime> {
+    type Error = TimestampError;
+
+    /// Fails if the year is not within -9999 to 9999.
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        let secs = |d: Duration| i64::try_from(d.as_secs()).or(Err(TimestampError::OutOfRange));
+        let (secs, nanos) = match time.duration_since(UNIX_EPOCH) {
+            Ok(d) => (secs(d)?, d.subsec_nanos()),
+            Err(e) => {
+                let d = e.duration();
+                match d.subsec_nanos() {
+                    0 => (-secs(d)?, 0),
+                    nanos => (-secs(d)? - 1, 1_000_000_000 - nanos),
+                }
+            }
+        };
+        Self::from_unix_timestamp(secs, nanos, 0).ok_or(TimestampError::OutOfRange)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<DateTime<Date, GlobalTime>> for SystemTime {
+    type Error = TimestampError;
+
+    /// Fails if the date is invalid
+    /// or the instant cannot be represented on this platform.
+    fn try_from(dt: DateTime<Date, GlobalTime>) -> Result<Self, Self::Error> {
+        let (secs, nanos) = dt.to_unix_timestamp().ok_or(TimestampError::InvalidDate)?;
+        if secs >= 0 {
+            UNIX_EPOCH.checked_add(Duration::new(secs as u64, nanos))
+        } else {
+            UNIX_EPOCH
+                .checked_sub(Duration::from_secs(secs.unsigned_abs()))
+                .and_then(|time| time.checked_add(Duration::from_nanos(nanos.into())))
+        }
+        .ok_or(TimestampError::OutOfRange)
+    }
+}
+
 #[derive(PartialEq, Clone, Debug)]
 pub enum PartialDateTime<D = ApproxDate, T = ApproxAnyTime>
 where
@@ -57,3 +312,581 @@ where
 }
 
 impl_fromstr_parse!(PartialDateTime<ApproxDate, ApproxAnyTime>, partial_datetime_approx_any_approx);
+
+impl<D, T> Valid for PartialDateTime<D, T>
+where
+    D: Datelike + Valid,
+    T: Timelike + Valid,
+{
+    fn is_valid(&self) -> bool {
+        match self {
+            PartialDateTime::Date(date) => date.is_valid(),
+            PartialDateTime::Time(time) => time.is_valid(),
+            PartialDateTime::DateTime(dt) => dt.is_valid(),
+        }
+    }
+}
+
+impl<D, T> fmt::Display for PartialDateTime<D, T>
+where
+    D: Datelike + fmt::Display,
+    T: Timelike + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartialDateTime::Date(date) => date.fmt(f),
+            PartialDateTime::Time(time) => time.fmt(f),
+            PartialDateTime::DateTime(dt) => dt.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn global(date: Date, hour: u8, minute: u8, timezone: i16) -> DateTime<Date, GlobalTime> {
+        DateTime {
+            date,
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime {
+                        hour,
+                        minute,
+                        second: 42,
+                    },
+                    fraction: 0.5,
+                },
+                timezone,
+            },
+        }
+    }
+
+    #[test]
+    fn with_offset() {
+        let dt = global(
+            Date::YMD(YmdDate {
+                year: 2018,
+                month: 8,
+                day: 2,
+            }),
+            5,
+            42,
+            5 * 60 + 30,
+        );
+        assert_eq!(
+            dt.with_offset(-8 * 60),
+            global(
+                Date::YMD(YmdDate {
+                    year: 2018,
+                    month: 8,
+                    day: 1,
+                }),
+                16,
+                12,
+                -8 * 60
+            )
+        );
+        assert_eq!(dt.with_offset(-8 * 60).with_offset(5 * 60 + 30), dt);
+    }
+
+    #[test]
+    fn checked_with_offset() {
+        let dt = global(
+            Date::YMD(YmdDate {
+                year: 9999,
+                month: 12,
+                day: 31,
+            }),
+            23,
+            0,
+            -5 * 60,
+        );
+        assert_eq!(dt.checked_to_utc(), None);
+        assert!(dt.checked_with_offset(-5 * 60).is_some());
+
+        let dt = global(
+            Date::YMD(YmdDate {
+                year: 2018,
+                month: 13,
+                day: 1,
+            }),
+            0,
+            0,
+            0,
+        );
+        assert_eq!(dt.checked_with_offset(60), None);
+    }
+
+    #[test]
+    fn with_offset_year() {
+        let dt = global(
+            Date::YMD(YmdDate {
+                year: 2018,
+                month: 12,
+                day: 31,
+            }),
+            22,
+            0,
+            -2 * 60,
+        );
+        assert_eq!(
+            dt.to_utc(),
+            global(
+                Date::YMD(YmdDate {
+                    year: 2019,
+                    month: 1,
+                    day: 1,
+                }),
+                0,
+                0,
+                0
+            )
+        );
+
+        let dt = global(Date::O(ODate { year: 2019, day: 1 }), 1, 0, 2 * 60);
+        assert_eq!(
+            dt.to_utc(),
+            global(
+                Date::O(ODate {
+                    year: 2018,
+                    day: 365,
+                }),
+                23,
+                0,
+                0
+            )
+        );
+    }
+
+    #[test]
+    fn with_offset_leap_day() {
+        let dt = global(
+            Date::YMD(YmdDate {
+                year: 2020,
+                month: 2,
+                day: 28,
+            }),
+            23,
+            30,
+            0,
+        );
+        assert_eq!(
+            dt.with_offset(60),
+            global(
+                Date::YMD(YmdDate {
+                    year: 2020,
+                    month: 2,
+                    day: 29,
+                }),
+                0,
+                30,
+                60
+            )
+        );
+
+        let dt = global(
+            Date::WD(WdDate {
+                year: 2020,
+                week: 9,
+                day: 6,
+            }),
+            23,
+            30,
+            0,
+        );
+        assert_eq!(
+            dt.with_offset(60),
+            global(
+                Date::WD(WdDate {
+                    year: 2020,
+                    week: 9,
+                    day: 7,
+                }),
+                0,
+                30,
+                60
+            )
+        );
+    }
+
+    #[test]
+    fn to_unix_timestamp() {
+        let dt = global(
+            Date::YMD(YmdDate {
+                year: 2018,
+                month: 8,
+                day: 2,
+            }),
+            13,
+            42,
+            0,
+        );
+        assert_eq!(dt.to_unix_timestamp(), Some((1_533_217_362, 500_000_000)));
+        assert_eq!(
+            dt.with_offset(5 * 60 + 30).to_unix_timestamp(),
+            Some((1_533_217_362, 500_000_000))
+        );
+        assert_eq!(
+            DateTime {
+                time: GlobalTime {
+                    timezone: 5 * 60 + 30,
+                    ..dt.time.clone()
+                },
+                ..dt
+            }
+            .to_unix_timestamp(),
+            Some((1_533_197_562, 500_000_000))
+        );
+
+        let dt = global(
+            Date::WD(WdDate {
+                year: 1970,
+                week: 1,
+                day: 3,
+            }),
+            23,
+            59,
+            0,
+        );
+        assert_eq!(dt.to_unix_timestamp(), Some((-18, 500_000_000)));
+
+        let dt = global(
+            Date::YMD(YmdDate {
+                year: 2018,
+                month: 13,
+                day: 1,
+            }),
+            0,
+            0,
+            0,
+        );
+        assert_eq!(dt.to_unix_timestamp(), None);
+    }
+
+    #[test]
+    fn from_unix_timestamp() {
+        assert_eq!(
+            DateTime::from_unix_timestamp(1_533_217_362, 500_000_000, 0),
+            Some(global(
+                Date::YMD(YmdDate {
+                    year: 2018,
+                    month: 8,
+                    day: 2,
+                }),
+                13,
+                42,
+                0
+            ))
+        );
+        assert_eq!(
+            DateTime::from_unix_timestamp(-18, 500_000_000, 60),
+            Some(global(
+                Date::YMD(YmdDate {
+                    year: 1970,
+                    month: 1,
+                    day: 1,
+                }),
+                0,
+                59,
+                60
+            ))
+        );
+        assert_eq!(DateTime::from_unix_timestamp(i64::MAX, 0, 60), None);
+        assert_eq!(DateTime::from_unix_timestamp(1 << 40, 0, 0), None);
+        assert_eq!(DateTime::from_unix_timestamp(0, 1_000_000_000, 0), None);
+
+        let dt = DateTime::from_unix_timestamp(0, 999_999_999, 0).unwrap();
+        assert!(dt.time.local.fraction < 1.);
+        assert_eq!(dt.to_string(), "1970-01-01T00:00:00.99999994Z");
+        assert_eq!(
+            DateTime::from_unix_timestamp(0, 123_456_789, 0)
+                .unwrap()
+                .to_unix_timestamp(),
+            Some((0, 123_456_791))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn try_from_system_time() {
+        assert_eq!(
+            DateTime::try_from(UNIX_EPOCH + Duration::new(1_533_217_362, 500_000_000)),
+            Ok(global(
+                Date::YMD(YmdDate {
+                    year: 2018,
+                    month: 8,
+                    day: 2,
+                }),
+                13,
+                42,
+                0
+            ))
+        );
+        assert_eq!(
+            DateTime::try_from(UNIX_EPOCH - Duration::new(17, 500_000_000)),
+            Ok(global(
+                Date::YMD(YmdDate {
+                    year: 1969,
+                    month: 12,
+                    day: 31,
+                }),
+                23,
+                59,
+                0
+            ))
+        );
+        assert_eq!(
+            DateTime::try_from(UNIX_EPOCH + Duration::from_secs(1 << 40)),
+            Err(TimestampError::OutOfRange)
+        );
+        assert!(
+            DateTime::try_from(UNIX_EPOCH + Duration::new(0, 999_999_999))
+                .unwrap()
+                .time
+                .local
+                .fraction
+                < 1.
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn try_into_system_time() {
+        let dt = global(
+            Date::YMD(YmdDate {
+                year: 2018,
+                month: 8,
+                day: 2,
+            }),
+            19,
+            12,
+            5 * 60 + 30,
+        );
+        assert_eq!(
+            SystemTime::try_from(dt),
+            Ok(UNIX_EPOCH + Duration::new(1_533_217_362, 500_000_000))
+        );
+
+        let dt = global(
+            Date::YMD(YmdDate {
+                year: 1969,
+                month: 12,
+                day: 31,
+            }),
+            23,
+            59,
+            0,
+        );
+        assert_eq!(
+            SystemTime::try_from(dt),
+            Ok(UNIX_EPOCH - Duration::new(17, 500_000_000))
+        );
+
+        let dt = global(
+            Date::YMD(YmdDate {
+                year: 2018,
+                month: 13,
+                day: 1,
+            }),
+            0,
+            0,
+            0,
+        );
+        assert_eq!(SystemTime::try_from(dt), Err(TimestampError::InvalidDate));
+    }
+
+    #[test]
+    #[cfg(feature = "leap-seconds")]
+    fn is_valid_strict() {
+        let leap = DateTime {
+            date: Date::YMD(YmdDate {
+                year: 2016,
+                month: 12,
+                day: 31,
+            }),
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime {
+                        hour: 23,
+                        minute: 59,
+                        second: 60,
+                    },
+                    fraction: 0.,
+                },
+                timezone: 0,
+            },
+        };
+        assert!(leap.is_valid_strict());
+        assert!(leap.with_offset(9 * 60).is_valid_strict());
+        assert!(leap.with_offset(-5 * 60 - 30).is_valid_strict());
+
+        assert!(!DateTime {
+            date: Date::YMD(YmdDate {
+                year: 2017,
+                month: 12,
+                day: 31,
+            }),
+            ..leap.clone()
+        }
+        .is_valid_strict());
+        assert!(!DateTime {
+            time: GlobalTime {
+                timezone: 60,
+                ..leap.time.clone()
+            },
+            ..leap.clone()
+        }
+        .is_valid_strict());
+        assert!(!DateTime {
+            date: Date::YMD(YmdDate {
+                year: 9999,
+                month: 12,
+                day: 31,
+            }),
+            time: GlobalTime {
+                timezone: -60,
+                ..leap.time.clone()
+            },
+        }
+        .is_valid_strict());
+        assert!(!DateTime {
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime {
+                        hour: 12,
+                        minute: 0,
+                        second: 60,
+                    },
+                    fraction: 0.,
+                },
+                timezone: 0,
+            },
+            ..leap
+        }
+        .is_valid_strict());
+    }
+
+    #[test]
+    fn normalize() {
+        let end_of_day = LocalTime {
+            naive: HmsTime {
+                hour: 24,
+                minute: 0,
+                second: 0,
+            },
+            fraction: 0.,
+        };
+        let start_of_day = LocalTime {
+            naive: HmsTime {
+                hour: 0,
+                minute: 0,
+                second: 0,
+            },
+            fraction: 0.,
+        };
+
+        assert_eq!(
+            DateTime {
+                date: Date::YMD(YmdDate {
+                    year: 2018,
+                    month: 12,
+                    day: 31,
+                }),
+                time: end_of_day.clone(),
+            }
+            .normalize(),
+            DateTime {
+                date: Date::YMD(YmdDate {
+                    year: 2019,
+                    month: 1,
+                    day: 1,
+                }),
+                time: start_of_day.clone(),
+            }
+        );
+        assert_eq!(
+            DateTime {
+                date: WdDate {
+                    year: 2020,
+                    week: 53,
+                    day: 7,
+                },
+                time: end_of_day.clone(),
+            }
+            .normalize(),
+            DateTime {
+                date: WdDate {
+                    year: 2021,
+                    week: 1,
+                    day: 1,
+                },
+                time: start_of_day.clone(),
+            }
+        );
+        assert_eq!(
+            DateTime {
+                date: ODate {
+                    year: 2020,
+                    day: 59,
+                },
+                time: end_of_day.clone(),
+            }
+            .normalize(),
+            DateTime {
+                date: ODate {
+                    year: 2020,
+                    day: 60,
+                },
+                time: start_of_day.clone(),
+            }
+        );
+
+        let dt = DateTime {
+            date: YmdDate {
+                year: 2018,
+                month: 8,
+                day: 2,
+            },
+            time: start_of_day,
+        };
+        assert_eq!(dt.normalize(), dt);
+
+        let dt = DateTime {
+            date: YmdDate {
+                year: 9999,
+                month: 12,
+                day: 31,
+            },
+            time: end_of_day,
+        };
+        assert_eq!(dt.normalize(), dt);
+    }
+
+    #[test]
+    fn display() {
+        for dt in &[
+            "2018-08-02T13:42:02.5Z",
+            "2018-W31-4T13:42+02:00",
+            "2018-214T13",
+        ] {
+            assert_eq!(
+                DateTime::<Date, ApproxAnyTime>::from_str(dt)
+                    .unwrap()
+                    .to_string(),
+                *dt
+            );
+        }
+        assert_eq!(
+            format!(
+                "{:#}",
+                DateTime::<Date, GlobalTime<HmTime>>::from_str("2018-08-02T13:42Z").unwrap()
+            ),
+            "20180802T1342Z"
+        );
+        for dt in &["2018-08", "12:30", "2018-08-02T12:30"] {
+            assert_eq!(PartialDateTime::from_str(dt).unwrap().to_string(), *dt);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{datetime::PartialDateTime, input::is_expression_byte, parse, Valid};
+use core::ops::Range;
+
+/// Finds the date, time and datetime expressions in free-form text.
+///
+/// An expression must stand on its own:
+/// it is neither preceded nor followed by a letter or digit,
+/// and does not continue an expression-like run such as `1.2.2018`.
+/// Expressions that do not exist, like `2018-13-45` or `25:00`, are skipped.
+///
+/// Plain numbers are valid years (`2018`) or centuries (`20`),
+/// so they are found as dates too;
+/// match on the result to keep only the kinds of expressions you need.
+///
+/// ```
+/// use iso_8601::{find_iter, PartialDateTime};
+///
+/// let text = "deployed 2018-08-02T13:42:02Z, rolled back at 14:05Z to 4.2.1";
+/// let found: Vec<_> = find_iter(text).map(|(range, _)| &text[range]).collect();
+/// assert_eq!(found, ["2018-08-02T13:42:02Z", "14:05Z"]);
+///
+/// let datetimes = find_iter("2018-08-02T13:42:02Z and 2018")
+///     .filter(|(_, value)| matches!(value, PartialDateTime::DateTime(_)))
+///     .count();
+/// assert_eq!(datetimes, 1);
+/// ```
+pub fn find_iter(text: &str) -> FindIter<'_> {
+    FindIter { text, pos: 0 }
+}
+
+/// Iterator over the expressions in some text. See `find_iter`.
+#[derive(Clone, Debug)]
+pub struct FindIter<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+/// Whether a character can continue an expression or word,
+/// so an expression cannot start after it or end before it.
+/// Letters and digits of any script count, like `ä` or `٣`.
+fn is_joined(c: char) -> bool {
+    c.is_alphanumeric() || "+-:.,_".contains(c)
+}
+
+/// Whether an expression can start with a byte:
+/// a digit or a sign, including the lead byte of `−` and `‐`.
+fn can_start(b: u8) -> bool {
+    b.is_ascii_digit() || b == b'+' || b == b'-' || b == 0xE2
+}
+
+impl<'a> Iterator for FindIter<'a> {
+    type Item = (Range<usize>, PartialDateTime);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.text.as_bytes();
+        while self.pos < bytes.len() {
+            let start = self.pos;
+            self.pos += 1;
+
+            // A start byte is never inside a UTF-8 sequence, so it splits the text.
+            if !can_start(bytes[start])
+                || self.text[..start]
+                    .chars()
+                    .next_back()
+                    .map_or(false, is_joined)
+            {
+                continue;
+            }
+
+            // Only look at the run of bytes that could belong to the expression
+            // so that a time designator further on is not mistaken for its own.
+            let token_end = bytes[start..]
+                .iter()
+                .position(|&b| !is_expression_byte(b))
+                .map_or(bytes.len(), |len| start + len);
+            let (rest, value) =
+                match parse::partial_datetime_approx_any_approx(&bytes[start..token_end]) {
+                    Ok(parsed) => parsed,
+                    Err(_) => continue,
+                };
+            let end = token_end - rest.len();
+            let next = self.text[end..].chars().next();
+            if next.map_or(false, char::is_alphanumeric) {
+                continue;
+            }
+            // Don't stop in the middle of a longer expression-like run
+            // such as a version number or a date in another notation.
+            if next.map_or(false, is_joined) && bytes.get(end + 1).map_or(false, u8::is_ascii_digit)
+            {
+                continue;
+            }
+            if !value.is_valid() {
+                continue;
+            }
+
+            self.pos = end;
+            return Some((start..end, value));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn found(text: &str) -> Vec<&str> {
+        find_iter(text).map(|(range, _)| &text[range]).collect()
+    }
+
+    #[test]
+    fn iter() {
+        let text = "On 2018-08-02 at 13:42 (20180802T134202Z), until 2018-W31-5.";
+        let mut iter = find_iter(text);
+        assert_eq!(
+            iter.next(),
+            Some((
+                3..13,
+                PartialDateTime::Date(ApproxDate::YMD(YmdDate {
+                    year: 2018,
+                    month: 8,
+                    day: 2,
+                }))
+            ))
+        );
+        assert_eq!(
+            iter.next(),
+            Some((
+                17..22,
+                PartialDateTime::Time(ApproxAnyTime::HM(AnyTime::Local(LocalTime {
+                    naive: HmTime {
+                        hour: 13,
+                        minute: 42,
+                    },
+                    fraction: 0.,
+                })))
+            ))
+        );
+        assert_eq!(
+            iter.next().map(|(range, _)| &text[range]),
+            Some("20180802T134202Z")
+        );
+        assert_eq!(
+            iter.next().map(|(range, _)| &text[range]),
+            Some("2018-W31-5")
+        );
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn boundaries() {
+        assert_eq!(found("2018-08-02"), ["2018-08-02"]);
+        assert_eq!(found("v2018-08-02 x2018 2018x 2018_1"), Vec::<&str>::new());
+        assert_eq!(found("1.2.2018 10.0.2018.1 1:2:3"), Vec::<&str>::new());
+        assert_eq!(found("(2018-08-02)"), ["2018-08-02"]);
+        assert_eq!(
+            found("2018-08-02, 2018-08-03"),
+            ["2018-08-02", "2018-08-03"]
+        );
+        assert_eq!(found("\u{2212}0044-03-15 BC"), ["\u{2212}0044-03-15"]);
+        assert_eq!(found("über 2018-08-02 äh"), ["2018-08-02"]);
+        assert_eq!(found("ä2018-08-02ü 2018é é2018"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(found("2018-13-45 25:00 2018-02-29"), Vec::<&str>::new());
+        assert_eq!(found("2018-02-30 2020-02-29"), ["2020-02-29"]);
+    }
+}
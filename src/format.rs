@@ -0,0 +1,101 @@
+/// Basic or extended format (2.3.3, 2.3.4).
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
+pub enum Format {
+    /// With the fewest separators, like `20180802T134202+0200`.
+    Basic,
+    /// With separators, like `2018-08-02T13:42:02+02:00`.
+    Extended,
+}
+
+/// Formats of the parts of an expression, as recorded while parsing.
+/// `None` for parts that are absent or read the same in both formats,
+/// like `2018`, `2018-08`, `13` or `Z`.
+///
+/// Returned with the value by [`parsers::partial_datetime_formats`](crate::parsers::partial_datetime_formats),
+/// and for every parsed type by [`layout::Parsed`](crate::layout::Parsed).
+///
+/// ```
+/// use iso_8601::{layout::Parsed, Format, GlobalTime};
+///
+/// let parsed: Parsed<GlobalTime> = "134202+02:00".parse().unwrap();
+/// assert_eq!(parsed.layout.formats.time, Some(Format::Basic));
+/// assert_eq!(parsed.layout.formats.timezone, Some(Format::Extended));
+/// assert!(parsed.layout.formats.is_mixed());
+/// ```
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Default, Hash)]
+pub struct Formats {
+    /// The format of the date, like `Basic` for `20180802`.
+    pub date: Option<Format>,
+    /// The format of the time of day, fraction included,
+    /// like `Extended` for `13:42:02.5`.
+    pub time: Option<Format>,
+    /// The format of the time zone offset, like `Basic` for `+0200`.
+    pub timezone: Option<Format>,
+}
+
+impl Formats {
+    /// Whether any part is in the format.
+    pub fn contains(&self, format: Format) -> bool {
+        [self.date, self.time, self.timezone].contains(&Some(format))
+    }
+
+    /// Whether some parts are in basic and others in extended format,
+    /// like `20180802T13:42:00`, which the standard does not allow.
+    pub fn is_mixed(&self) -> bool {
+        self.contains(Format::Basic) && self.contains(Format::Extended)
+    }
+
+    /// The format of the whole expression.
+    /// `None` if it is mixed or reads the same in both formats.
+    pub fn format(&self) -> Option<Format> {
+        match (
+            self.contains(Format::Basic),
+            self.contains(Format::Extended),
+        ) {
+            (true, false) => Some(Format::Basic),
+            (false, true) => Some(Format::Extended),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Format::*;
+
+    fn formats(date: Option<Format>, time: Option<Format>, timezone: Option<Format>) -> Formats {
+        Formats {
+            date,
+            time,
+            timezone,
+        }
+    }
+
+    #[test]
+    fn contains() {
+        assert!(formats(Some(Basic), Some(Extended), None).contains(Basic));
+        assert!(formats(Some(Basic), Some(Extended), None).contains(Extended));
+        assert!(!formats(None, None, Some(Basic)).contains(Extended));
+        assert!(!Formats::default().contains(Basic));
+    }
+
+    #[test]
+    fn mixed() {
+        for mixed in &[
+            formats(Some(Basic), Some(Extended), None),
+            formats(Some(Extended), Some(Basic), None),
+            formats(None, Some(Basic), Some(Extended)),
+        ] {
+            assert!(mixed.is_mixed(), "{:?}", mixed);
+            assert_eq!(mixed.format(), None);
+        }
+        assert_eq!(
+            formats(Some(Extended), Some(Extended), None).format(),
+            Some(Extended)
+        );
+        assert_eq!(formats(Some(Basic), None, None).format(), Some(Basic));
+        assert_eq!(Formats::default().format(), None);
+        assert!(!Formats::default().is_mixed());
+    }
+}
@@ -0,0 +1,323 @@
+//! Parsing from string or byte input, complete or streaming.
+//!
+//! ```
+//! use iso_8601::{input::{Error, Parse}, Date, YmdDate};
+//!
+//! let date = Date::YMD(YmdDate { year: 2018, month: 8, day: 2 });
+//! assert_eq!(Date::parse_complete("2018-08-02"), Ok(("", date.clone())));
+//! assert_eq!(Date::parse_streaming(&b"2018-08-02"[..]), Err(Error::Incomplete));
+//! assert_eq!(Date::parse_streaming(&b"2018-08-02 "[..]), Ok((&b" "[..], date)));
+//! ```
+
+use core::fmt;
+use nom::IResult;
+
+/// Why input could not be parsed.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Error {
+    /// The input does not start with a valid expression.
+    Invalid,
+    /// The input ends before it can be decided what it starts with.
+    /// Only returned when streaming.
+    Incomplete,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::Invalid => "invalid ISO 8601 expression",
+            Error::Incomplete => "incomplete ISO 8601 expression",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for &str {}
+    impl Sealed for &[u8] {}
+}
+
+/// Input that can be parsed: `&str` or `&[u8]`.
+pub trait Input<'a>: Copy + sealed::Sealed {
+    fn as_bytes(self) -> &'a [u8];
+
+    /// The input after the first `n` bytes.
+    fn skip(self, n: usize) -> Self;
+}
+
+impl<'a> Input<'a> for &'a str {
+    fn as_bytes(self) -> &'a [u8] {
+        str::as_bytes(self)
+    }
+
+    /// Parsers only stop at character boundaries.
+    fn skip(self, n: usize) -> Self {
+        &self[n..]
+    }
+}
+
+impl<'a> Input<'a> for &'a [u8] {
+    fn as_bytes(self) -> &'a [u8] {
+        self
+    }
+
+    fn skip(self, n: usize) -> Self {
+        &self[n..]
+    }
+}
+
+/// Parsing a value from the start of some input.
+pub trait Parse: Sized {
+    /// Parses input that is known to end where it ends,
+    /// like a whole string or file.
+    /// Returns the rest of the input after the expression.
+    ///
+    /// Never returns `Error::Incomplete`.
+    fn parse_complete<'a, I: Input<'a>>(i: I) -> Result<(I, Self), Error>;
+
+    /// Parses input that more may be appended to, like a network buffer.
+    /// Returns the rest of the input after the expression.
+    ///
+    /// Returns `Error::Incomplete` until the input contains a byte
+    /// that cannot be part of an expression, so that more input
+    /// can never change the result.
+    fn parse_streaming<'a, I: Input<'a>>(i: I) -> Result<(I, Self), Error>;
+}
+
+/// Whether a byte can be part of an expression.
+/// Any non-ASCII byte could belong to a Unicode minus sign or hyphen.
+pub(crate) fn is_expression_byte(b: u8) -> bool {
+    b.is_ascii_digit() || b"+-:.,TWZ".contains(&b) || !b.is_ascii()
+}
+
+pub(crate) fn complete<'a, I, T>(
+    i: I,
+    parser: fn(&[u8]) -> IResult<&[u8], T>,
+) -> Result<(I, T), Error>
+where
+    I: Input<'a>,
+{
+    let bytes = i.as_bytes();
+    match parser(bytes) {
+        Ok((rest, value)) => Ok((i.skip(bytes.len() - rest.len()), value)),
+        Err(_) => Err(Error::Invalid),
+    }
+}
+
+pub(crate) fn streaming<'a, I, T>(
+    i: I,
+    parser: fn(&[u8]) -> IResult<&[u8], T>,
+) -> Result<(I, T), Error>
+where
+    I: Input<'a>,
+{
+    let bytes = i.as_bytes();
+    let end = bytes
+        .iter()
+        .position(|&b| !is_expression_byte(b))
+        .ok_or(Error::Incomplete)?;
+    let (rest, value) = complete(&bytes[..end], parser)?;
+    Ok((i.skip(end - rest.len()), value))
+}
+
+/// Reads whitespace- or otherwise delimited expressions
+/// from a buffered reader as they arrive.
+///
+/// Expressions that fail to parse are returned as
+/// `io::ErrorKind::InvalidData` and skipped.
+///
+/// ```
+/// use iso_8601::{input::Reader, Date, ODate};
+///
+/// let input = &b"2018-08-02\n2018-W31-4\n2018-214\n"[..];
+/// let dates = Reader::<_, Date>::new(input).collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(dates.len(), 3);
+/// assert_eq!(dates[2], Date::O(ODate { year: 2018, day: 214 }));
+/// ```
+#[cfg(feature = "std")]
+pub struct Reader<R, T> {
+    reader: R,
+    buf: Vec<u8>,
+    value: core::marker::PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "std")]
+impl<R, T> Reader<R, T>
+where
+    R: std::io::BufRead,
+    T: Parse,
+{
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: Vec::new(),
+            value: core::marker::PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn invalid(&mut self) -> std::io::Error {
+        let end = self
+            .buf
+            .iter()
+            .position(|&b| !is_expression_byte(b))
+            .unwrap_or(self.buf.len());
+        self.buf.drain(..end);
+        std::io::Error::new(std::io::ErrorKind::InvalidData, Error::Invalid)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R, T> Iterator for Reader<R, T>
+where
+    R: std::io::BufRead,
+    T: Parse,
+{
+    type Item = std::io::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self
+                .buf
+                .iter()
+                .position(|&b| is_expression_byte(b))
+                .unwrap_or(self.buf.len());
+            self.buf.drain(..start);
+
+            if !self.buf.is_empty() {
+                match T::parse_streaming(&self.buf[..]) {
+                    Ok((rest, value)) => {
+                        let end = self.buf.len() - rest.len();
+                        self.buf.drain(..end);
+                        return Some(Ok(value));
+                    }
+                    Err(Error::Invalid) => return Some(Err(self.invalid())),
+                    Err(Error::Incomplete) => {}
+                }
+            }
+
+            let data = match self.reader.fill_buf() {
+                Ok(data) => data,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => return Some(Err(err)),
+            };
+            if data.is_empty() {
+                if self.buf.is_empty() {
+                    return None;
+                }
+                return Some(match T::parse_complete(&self.buf[..]) {
+                    Ok((rest, value)) => {
+                        let end = self.buf.len() - rest.len();
+                        self.buf.drain(..end);
+                        Ok(value)
+                    }
+                    Err(_) => Err(self.invalid()),
+                });
+            }
+            let len = data.len();
+            self.buf.extend_from_slice(data);
+            self.reader.consume(len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn ymd(year: i16, month: u8, day: u8) -> Date {
+        Date::YMD(YmdDate { year, month, day })
+    }
+
+    #[test]
+    fn complete() {
+        assert_eq!(
+            Date::parse_complete("2018-08-02"),
+            Ok(("", ymd(2018, 8, 2)))
+        );
+        assert_eq!(
+            Date::parse_complete(&b"20180802T"[..]),
+            Ok((&b"T"[..], ymd(2018, 8, 2)))
+        );
+        assert_eq!(
+            YmdDate::parse_complete("\u{2212}0044-03-15 BC"),
+            Ok((
+                " BC",
+                YmdDate {
+                    year: -44,
+                    month: 3,
+                    day: 15,
+                }
+            ))
+        );
+        for input in &["", "2018-08", "2018-0", "13:42"] {
+            assert_eq!(YmdDate::parse_complete(*input), Err(Error::Invalid));
+        }
+    }
+
+    #[test]
+    fn streaming() {
+        for input in &["", "2018", "2018-08", "2018-08-02", "\u{2212}"] {
+            assert_eq!(YmdDate::parse_streaming(*input), Err(Error::Incomplete));
+        }
+        assert_eq!(
+            Date::parse_streaming("2018-08-02\n"),
+            Ok(("\n", ymd(2018, 8, 2)))
+        );
+        assert_eq!(
+            Date::parse_streaming("2018-08-02Tx"),
+            Ok(("Tx", ymd(2018, 8, 2)))
+        );
+        assert_eq!(YmdDate::parse_streaming("2018-08 "), Err(Error::Invalid));
+        assert_eq!(
+            <DateTime<Date, GlobalTime>>::parse_streaming(&b"2018-08-02T13:42:02Z,"[..])
+                .map(|(rest, _)| rest),
+            Err(Error::Incomplete)
+        );
+        assert_eq!(
+            <DateTime<Date, GlobalTime>>::parse_streaming(&b"2018-08-02T13:42:02Z;"[..])
+                .map(|(rest, _)| rest),
+            Ok(&b";"[..])
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader() {
+        use std::io::{BufReader, ErrorKind};
+
+        let input = &b"2018-08-02 20180803; 2018-13-99x\n2018-W31-4;20 2018-214"[..];
+        let mut reader = Reader::<_, Date>::new(BufReader::with_capacity(3, input));
+        assert_eq!(reader.next().unwrap().unwrap(), ymd(2018, 8, 2));
+        assert_eq!(reader.next().unwrap().unwrap(), ymd(2018, 8, 3));
+        assert_eq!(reader.next().unwrap().unwrap(), ymd(2018, 13, 99));
+        assert_eq!(
+            reader.next().unwrap().unwrap(),
+            Date::WD(WdDate {
+                year: 2018,
+                week: 31,
+                day: 4,
+            })
+        );
+        assert_eq!(
+            reader.next().unwrap().unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert_eq!(
+            reader.next().unwrap().unwrap(),
+            Date::O(ODate {
+                year: 2018,
+                day: 214,
+            })
+        );
+        assert!(reader.next().is_none());
+    }
+}
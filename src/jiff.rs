@@ -0,0 +1,498 @@
+#![cfg(feature = "jiff")]
+
+extern crate jiff;
+
+use self::jiff::{
+    civil,
+    tz::{AmbiguousOffset, Disambiguation, Offset, TimeZone},
+    Timestamp, Zoned,
+};
+use crate::{EndOfDay, Valid};
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+};
+
+/// Reasons a conversion between this crate and jiff can fail.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Error {
+    /// The date does not exist or is out of range.
+    InvalidDate,
+    /// The time of day does not exist
+    /// or is a leap second, which jiff does not support.
+    InvalidTime,
+    /// The difference from UTC is out of range.
+    InvalidOffset,
+    /// The time zone is not in the time zone database.
+    UnknownTimeZone,
+    /// The local time is skipped in the time zone,
+    /// for example when clocks are set forward.
+    NonexistentLocalTime,
+    /// The local time occurs twice in the time zone,
+    /// for example when clocks are set back.
+    AmbiguousLocalTime,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidDate => "invalid or out-of-range date",
+            Error::InvalidTime => "invalid or unsupported time of day",
+            Error::InvalidOffset => "out-of-range difference from UTC",
+            Error::UnknownTimeZone => "unknown time zone",
+            Error::NonexistentLocalTime => "local time does not exist in the time zone",
+            Error::AmbiguousLocalTime => "local time is ambiguous in the time zone",
+        })
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn year(year: i16) -> Result<i16, Error> {
+    match year {
+        -9999..=9999 => Ok(year),
+        _ => Err(Error::InvalidDate),
+    }
+}
+
+impl TryFrom<crate::YmdDate> for civil::Date {
+    type Error = Error;
+
+    fn try_from(date: crate::YmdDate) -> Result<Self, Self::Error> {
+        civil::Date::new(
+            year(date.year)?,
+            date.month.try_into().or(Err(Error::InvalidDate))?,
+            date.day.try_into().or(Err(Error::InvalidDate))?,
+        )
+        .or(Err(Error::InvalidDate))
+    }
+}
+
+impl TryFrom<crate::WdDate> for civil::Date {
+    type Error = Error;
+
+    fn try_from(date: crate::WdDate) -> Result<Self, Self::Error> {
+        let weekday = date
+            .day
+            .try_into()
+            .ok()
+            .and_then(|day| civil::Weekday::from_monday_one_offset(day).ok())
+            .ok_or(Error::InvalidDate)?;
+        civil::ISOWeekDate::new(
+            year(date.year)?,
+            date.week.try_into().or(Err(Error::InvalidDate))?,
+            weekday,
+        )
+        .map(civil::ISOWeekDate::date)
+        .or(Err(Error::InvalidDate))
+    }
+}
+
+impl TryFrom<crate::ODate> for civil::Date {
+    type Error = Error;
+
+    fn try_from(date: crate::ODate) -> Result<Self, Self::Error> {
+        civil::Date::new(year(date.year)?, 1, 1)
+            .and_then(|first| {
+                first
+                    .with()
+                    .day_of_year(date.day.try_into().unwrap_or(i16::MAX))
+                    .build()
+            })
+            .or(Err(Error::InvalidDate))
+    }
+}
+
+impl TryFrom<crate::Date> for civil::Date {
+    type Error = Error;
+
+    fn try_from(date: crate::Date) -> Result<Self, Self::Error> {
+        match date {
+            crate::Date::YMD(date) => date.try_into(),
+            crate::Date::WD(date) => date.try_into(),
+            crate::Date::O(date) => date.try_into(),
+        }
+    }
+}
+
+impl From<civil::Date> for crate::YmdDate {
+    fn from(date: civil::Date) -> Self {
+        Self {
+            year: date.year(),
+            month: date.month() as u8,
+            day: date.day() as u8,
+        }
+    }
+}
+
+impl From<civil::Date> for crate::WdDate {
+    fn from(date: civil::Date) -> Self {
+        let date = date.iso_week_date();
+        Self {
+            year: date.year(),
+            week: date.week() as u8,
+            day: date.weekday().to_monday_one_offset() as u8,
+        }
+    }
+}
+
+impl From<civil::Date> for crate::ODate {
+    fn from(date: civil::Date) -> Self {
+        Self {
+            year: date.year(),
+            day: date.day_of_year() as u16,
+        }
+    }
+}
+
+/// Calendar date representation.
+impl From<civil::Date> for crate::Date {
+    fn from(date: civil::Date) -> Self {
+        crate::Date::YMD(date.into())
+    }
+}
+
+/// Fails on leap seconds and the end of a day (`24:00`).
+impl TryFrom<crate::LocalTime> for civil::Time {
+    type Error = Error;
+
+    fn try_from(time: crate::LocalTime) -> Result<Self, Self::Error> {
+        if !time.is_valid() {
+            return Err(Error::InvalidTime);
+        }
+
+        civil::Time::new(
+            time.naive.hour as i8,
+            time.naive.minute as i8,
+            time.naive.second as i8,
+            time.nanosecond() as i32,
+        )
+        .or(Err(Error::InvalidTime))
+    }
+}
+
+impl TryFrom<crate::HmsTime> for civil::Time {
+    type Error = Error;
+
+    fn try_from(time: crate::HmsTime) -> Result<Self, Self::Error> {
+        crate::LocalTime {
+            naive: time,
+            fraction: 0.,
+        }
+        .try_into()
+    }
+}
+
+impl From<civil::Time> for crate::LocalTime {
+    fn from(time: civil::Time) -> Self {
+        Self {
+            naive: time.into(),
+            fraction: crate::datetime::fraction(time.subsec_nanosecond() as u32),
+        }
+    }
+}
+
+/// Drops fractions of a second.
+impl From<civil::Time> for crate::HmsTime {
+    fn from(time: civil::Time) -> Self {
+        Self {
+            hour: time.hour() as u8,
+            minute: time.minute() as u8,
+            second: time.second() as u8,
+        }
+    }
+}
+
+impl<N> TryFrom<crate::GlobalTime<N>> for Offset
+where
+    N: crate::NaiveTime,
+{
+    type Error = Error;
+
+    fn try_from(time: crate::GlobalTime<N>) -> Result<Self, Self::Error> {
+        Offset::from_seconds(i32::from(time.timezone) * 60).or(Err(Error::InvalidOffset))
+    }
+}
+
+/// Fails if the offset is not a whole number of minutes.
+impl TryFrom<(civil::Time, Offset)> for crate::GlobalTime {
+    type Error = Error;
+
+    fn try_from((time, offset): (civil::Time, Offset)) -> Result<Self, Self::Error> {
+        let seconds = offset.seconds();
+        if seconds % 60 != 0 {
+            return Err(Error::InvalidOffset);
+        }
+        Ok(Self {
+            local: time.into(),
+            timezone: (seconds / 60) as i16,
+        })
+    }
+}
+
+/// Maps the end of a day (`24:00`) to the beginning of the next one.
+impl TryFrom<crate::DateTime<crate::Date, crate::LocalTime>> for civil::DateTime {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::LocalTime>) -> Result<Self, Self::Error> {
+        let date = civil::Date::try_from(dt.date)?;
+        Ok(match dt.time.start_of_next_day() {
+            Some(time) => date
+                .tomorrow()
+                .or(Err(Error::InvalidDate))?
+                .to_datetime(time.try_into()?),
+            None => date.to_datetime(dt.time.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::GlobalTime>> for Timestamp {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Result<Self, Self::Error> {
+        let offset = Offset::try_from(dt.time.clone())?;
+        offset
+            .to_timestamp(civil::DateTime::try_from(crate::DateTime {
+                date: dt.date,
+                time: dt.time.local,
+            })?)
+            .or(Err(Error::InvalidDate))
+    }
+}
+
+/// In a time zone with the fixed offset of the global time.
+impl TryFrom<crate::DateTime<crate::Date, crate::GlobalTime>> for Zoned {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Result<Self, Self::Error> {
+        let offset = Offset::try_from(dt.time.clone())?;
+        Ok(Timestamp::try_from(dt)?.to_zoned(TimeZone::fixed(offset)))
+    }
+}
+
+impl crate::DateTime<crate::Date, crate::LocalTime> {
+    /// Resolves this local date and time in the given time zone.
+    ///
+    /// With [`Disambiguation::Reject`], times skipped or repeated
+    /// by a transition of the time zone are reported as
+    /// [`Error::NonexistentLocalTime`] and [`Error::AmbiguousLocalTime`].
+    pub fn to_zoned(
+        &self,
+        time_zone: TimeZone,
+        disambiguation: Disambiguation,
+    ) -> Result<Zoned, Error> {
+        let ambiguous = time_zone.to_ambiguous_zoned(self.clone().try_into()?);
+        if let Disambiguation::Reject = disambiguation {
+            match ambiguous.offset() {
+                AmbiguousOffset::Gap { .. } => return Err(Error::NonexistentLocalTime),
+                AmbiguousOffset::Fold { .. } => return Err(Error::AmbiguousLocalTime),
+                AmbiguousOffset::Unambiguous { .. } => {}
+            }
+        }
+        ambiguous
+            .disambiguate(disambiguation)
+            .or(Err(Error::InvalidDate))
+    }
+
+    /// Like [`to_zoned`](Self::to_zoned) with a time zone
+    /// looked up by its IANA name, for example `America/New_York`.
+    pub fn in_tz(
+        &self,
+        time_zone_name: &str,
+        disambiguation: Disambiguation,
+    ) -> Result<Zoned, Error> {
+        let time_zone = TimeZone::get(time_zone_name).or(Err(Error::UnknownTimeZone))?;
+        self.to_zoned(time_zone, disambiguation)
+    }
+}
+
+macro_rules! impl_from_jiff {
+    ($($date:ty),*) => {
+        $(
+            impl From<civil::DateTime> for crate::DateTime<$date, crate::LocalTime> {
+                fn from(dt: civil::DateTime) -> Self {
+                    Self {
+                        date: dt.date().into(),
+                        time: dt.time().into(),
+                    }
+                }
+            }
+
+            /// Keeps the local time and offset of the zoned value.
+            /// Fails if the offset is not a whole number of minutes.
+            impl TryFrom<&Zoned> for crate::DateTime<$date, crate::GlobalTime> {
+                type Error = Error;
+
+                fn try_from(zoned: &Zoned) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        date: zoned.date().into(),
+                        time: (zoned.time(), zoned.offset()).try_into()?,
+                    })
+                }
+            }
+
+            impl TryFrom<Zoned> for crate::DateTime<$date, crate::GlobalTime> {
+                type Error = Error;
+
+                fn try_from(zoned: Zoned) -> Result<Self, Self::Error> {
+                    Self::try_from(&zoned)
+                }
+            }
+
+            /// In UTC.
+            impl From<Timestamp> for crate::DateTime<$date, crate::GlobalTime> {
+                fn from(timestamp: Timestamp) -> Self {
+                    let dt = Offset::UTC.to_datetime(timestamp);
+                    Self {
+                        date: dt.date().into(),
+                        time: crate::GlobalTime {
+                            local: dt.time().into(),
+                            timezone: 0,
+                        },
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_jiff!(crate::Date, crate::YmdDate, crate::WdDate, crate::ODate);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn local(s: &str) -> crate::DateTime<crate::Date, crate::LocalTime> {
+        crate::DateTime::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn date() {
+        let expected = civil::date(2018, 8, 2);
+        for date in &["2018-08-02", "2018-W31-4", "2018-214"] {
+            let date = crate::Date::from_str(date).unwrap();
+            assert_eq!(civil::Date::try_from(date), Ok(expected));
+        }
+
+        assert_eq!(
+            crate::WdDate::from(civil::date(2021, 1, 3)),
+            crate::WdDate {
+                year: 2020,
+                week: 53,
+                day: 7,
+            }
+        );
+        assert_eq!(
+            crate::ODate::from(expected),
+            crate::ODate {
+                year: 2018,
+                day: 214,
+            }
+        );
+        assert_eq!(
+            civil::Date::try_from(crate::ODate {
+                year: 2018,
+                day: 400,
+            }),
+            Err(Error::InvalidDate)
+        );
+        assert_eq!(
+            civil::Date::try_from(crate::YmdDate {
+                year: 10_000,
+                month: 1,
+                day: 1,
+            }),
+            Err(Error::InvalidDate)
+        );
+    }
+
+    #[test]
+    fn time() {
+        let time = crate::LocalTime::from_str("13:42:02.5").unwrap();
+        let expected = civil::time(13, 42, 2, 500_000_000);
+        assert_eq!(civil::Time::try_from(time.clone()), Ok(expected));
+        assert_eq!(crate::LocalTime::from(expected), time);
+
+        let end = civil::time(13, 42, 2, 999_999_999);
+        let time = crate::LocalTime::from(end);
+        assert!(time.is_valid());
+        // An `f32` only keeps about seven digits of the fraction.
+        let back = civil::Time::try_from(time).unwrap();
+        assert_eq!(back.second(), 2);
+        assert!(back.subsec_nanosecond() > 999_999_900);
+
+        assert_eq!(
+            civil::Time::try_from(crate::LocalTime::from_str("23:59:60").unwrap()),
+            Err(Error::InvalidTime)
+        );
+    }
+
+    #[test]
+    fn datetime() {
+        assert_eq!(
+            civil::DateTime::try_from(local("2018-12-31T24:00:00")),
+            Ok(civil::date(2019, 1, 1).at(0, 0, 0, 0))
+        );
+        assert_eq!(
+            crate::DateTime::<crate::Date, crate::LocalTime>::from(
+                civil::date(2018, 8, 2).at(13, 42, 2, 0)
+            ),
+            local("2018-08-02T13:42:02")
+        );
+
+        let dt = crate::DateTime::<crate::Date, crate::GlobalTime>::from_str(
+            "2018-08-02T13:42:02+02:00",
+        )
+        .unwrap();
+        let timestamp = Timestamp::from_second(1_533_210_122).unwrap();
+        assert_eq!(Timestamp::try_from(dt.clone()), Ok(timestamp));
+        assert_eq!(
+            crate::DateTime::from(timestamp),
+            crate::DateTime::<crate::Date, crate::GlobalTime>::from_str("2018-08-02T11:42:02Z")
+                .unwrap()
+        );
+
+        let zoned = Zoned::try_from(dt.clone()).unwrap();
+        assert_eq!(zoned.timestamp(), timestamp);
+        assert_eq!(crate::DateTime::try_from(&zoned), Ok(dt));
+    }
+
+    #[test]
+    fn to_zoned() {
+        let zone = "America/New_York";
+        assert_eq!(
+            local("2018-08-02T13:42:02")
+                .in_tz(zone, Disambiguation::Reject)
+                .map(|zoned| zoned.offset()),
+            Ok(Offset::from_hours(-4).unwrap())
+        );
+
+        let gap = local("2018-03-11T02:30:00");
+        assert_eq!(
+            gap.in_tz(zone, Disambiguation::Reject),
+            Err(Error::NonexistentLocalTime)
+        );
+        assert_eq!(
+            gap.in_tz(zone, Disambiguation::Later)
+                .map(|zoned| zoned.datetime()),
+            Ok(civil::date(2018, 3, 11).at(3, 30, 0, 0))
+        );
+
+        let fold = local("2018-11-04T01:30:00");
+        assert_eq!(
+            fold.in_tz(zone, Disambiguation::Reject),
+            Err(Error::AmbiguousLocalTime)
+        );
+        assert_eq!(
+            fold.in_tz(zone, Disambiguation::Earlier)
+                .map(|zoned| zoned.offset()),
+            Ok(Offset::from_hours(-4).unwrap())
+        );
+
+        assert_eq!(
+            fold.in_tz("Nowhere/Atlantis", Disambiguation::Compatible),
+            Err(Error::UnknownTimeZone)
+        );
+    }
+}
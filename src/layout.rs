@@ -0,0 +1,703 @@
+//! Values together with the layout they were written in.
+//!
+//! Parsing keeps only what an expression means, so `Display` writes
+//! every value in one canonical layout. `Parsed` also keeps how it was written:
+//! the format of each part, the sign characters, the time designator
+//! and the fraction digits, and writes the value back the same way.
+//!
+//! ```
+//! use iso_8601::{layout::Parsed, PartialDateTime};
+//!
+//! for input in &[
+//!     "2018-08-02T13:42:02.500+02:00",
+//!     "20180802T134202Z",
+//!     "\u{2212}0044-03-15T12+01",
+//!     "T13:42",
+//! ] {
+//!     let parsed: Parsed<PartialDateTime> = input.parse().unwrap();
+//!     assert_eq!(parsed.to_string(), *input);
+//! }
+//! ```
+
+use crate::{
+    date::*,
+    datetime::*,
+    format::{Format, Formats},
+    input::{Error, Input, Parse},
+    parse,
+    time::*,
+    Sign,
+};
+use core::{fmt, str::FromStr};
+
+/// How an expression was written.
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
+pub struct Layout {
+    /// Basic or extended format of each part.
+    pub formats: Formats,
+    /// Whether the time has the `T` designator.
+    pub designator: bool,
+    /// The sign of the year or century, if it has one.
+    pub year_sign: Option<Sign>,
+    /// Digits of the decimal fraction of the time, `0` if it has none.
+    pub fraction_digits: u8,
+    /// The first 19 digits of the fraction as an integer,
+    /// like `50` for `.050`.
+    pub fraction: u64,
+    /// The difference from UTC, `None` for local time.
+    pub timezone: Option<Timezone>,
+}
+
+/// How a difference from UTC was written (4.2.4, 4.2.5.1).
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
+pub enum Timezone {
+    /// `Z`
+    Z,
+    /// Hours only, like `+02`.
+    H(Sign),
+    /// Hours and minutes, like `+02:00` or `+0200`.
+    HM(Sign),
+}
+
+impl Layout {
+    /// Reads the layout of an expression from its text
+    /// and whether it has a date.
+    fn of(i: &[u8], date: bool) -> Self {
+        let (formats, time_text) = match parse::partial_datetime_formats_of(i, date) {
+            Ok((_, (_, formats, time_text))) => (formats, time_text),
+            Err(_) => (Formats::default(), &i[i.len()..]),
+        };
+        let year_sign = if date {
+            parse::sign(i).ok().map(|(_, sign)| sign)
+        } else {
+            None
+        };
+
+        let zone = time_text
+            .iter()
+            .position(|&b| matches!(b, b'Z' | b'+' | b'-' | 0xE2))
+            .unwrap_or(time_text.len());
+        let (local, zone) = time_text.split_at(zone);
+        let digits = local
+            .iter()
+            .position(|&b| b == b'.')
+            .map_or(&[][..], |dot| {
+                let digits = &local[dot + 1..];
+                &digits[..digits.iter().take_while(|b| b.is_ascii_digit()).count()]
+            });
+        let fraction = digits
+            .iter()
+            .take(FRACTION_DIGITS.into())
+            .fold(0, |value, b| value * 10 + u64::from(b - b'0'));
+        let timezone = match zone.first() {
+            None => None,
+            Some(b'Z') => Some(Timezone::Z),
+            Some(_) => parse::sign(zone).ok().map(|(rest, sign)| {
+                if rest.iter().filter(|b| b.is_ascii_digit()).count() > 2 {
+                    Timezone::HM(sign)
+                } else {
+                    Timezone::H(sign)
+                }
+            }),
+        };
+
+        Self {
+            formats,
+            designator: time_text.first() == Some(&b'T'),
+            year_sign,
+            fraction_digits: digits.len().min(u8::MAX.into()) as u8,
+            fraction,
+            timezone,
+        }
+    }
+}
+
+/// A value and the layout it was parsed from.
+///
+/// `Display` writes the value in its layout,
+/// so an unchanged value reproduces its input byte for byte.
+/// Where a changed value no longer fits the layout,
+/// like a negative year with a `+` sign or a difference from UTC
+/// of `+05:30` written as hours only, that part is written as
+/// the value's own `Display` would.
+///
+/// Up to 19 fraction digits are written back as they were
+/// while the fraction still reads the same.
+/// Longer or changed fractions are rounded to as many digits,
+/// rounding down rather than up to a whole second.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Parsed<T> {
+    pub value: T,
+    pub layout: Layout,
+}
+
+impl<T> Parsed<T>
+where
+    T: sealed::Expression,
+{
+    /// `i` is the input and `rest` what is left of it after the value.
+    fn new<'a, I: Input<'a>>(i: I, rest: I, value: T) -> Self {
+        let i = i.as_bytes();
+        Self {
+            layout: Layout::of(&i[..i.len() - rest.as_bytes().len()], value.has_date()),
+            value,
+        }
+    }
+}
+
+impl<T> Parse for Parsed<T>
+where
+    T: Parse + sealed::Expression,
+{
+    fn parse_complete<'a, I: Input<'a>>(i: I) -> Result<(I, Self), Error> {
+        let (rest, value) = T::parse_complete(i)?;
+        Ok((rest, Self::new(i, rest, value)))
+    }
+
+    fn parse_streaming<'a, I: Input<'a>>(i: I) -> Result<(I, Self), Error> {
+        let (rest, value) = T::parse_streaming(i)?;
+        Ok((rest, Self::new(i, rest, value)))
+    }
+}
+
+impl<T> FromStr for Parsed<T>
+where
+    T: Parse + sealed::Expression,
+{
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_complete(s).map(|x| x.1).or(Err(()))
+    }
+}
+
+impl<T> fmt::Display for Parsed<T>
+where
+    T: sealed::Expression,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.write(&self.layout, f)
+    }
+}
+
+mod sealed {
+    use super::Layout;
+    use core::fmt;
+
+    pub trait DatePart {
+        fn write_date(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result;
+    }
+
+    /// Writes the time without the designator.
+    pub trait TimePart {
+        fn write_time(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result;
+    }
+
+    pub trait Expression {
+        /// Whether the value has a date.
+        fn has_date(&self) -> bool;
+
+        fn write(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result;
+    }
+}
+
+use sealed::{DatePart, Expression, TimePart};
+
+fn separator(format: Option<Format>, separator: &'static str) -> &'static str {
+    if format == Some(Format::Basic) {
+        ""
+    } else {
+        separator
+    }
+}
+
+/// Picks the written sign if it fits the value,
+/// and otherwise the one `Display` would write, if any.
+fn sign(written: Option<Sign>, value: i16, expanded: bool) -> Option<Sign> {
+    match written {
+        Some(sign) if value == 0 || sign.is_negative() == (value < 0) => Some(sign),
+        _ if value < 0 => Some(Sign::HyphenMinus),
+        _ if expanded => Some(Sign::Plus),
+        _ => None,
+    }
+}
+
+fn write_year(year: i16, width: usize, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+    if let Some(sign) = sign(layout.year_sign, year, year > 9999) {
+        fmt::Display::fmt(&sign, f)?;
+    }
+    write!(f, "{:0width$}", year.unsigned_abs(), width = width)
+}
+
+/// The fraction digits a `Layout` keeps.
+const FRACTION_DIGITS: u8 = 19;
+
+/// The `f32` that fraction digits read as, the same way the parsers read them.
+fn read_fraction(value: u64, digits: u8) -> f32 {
+    let mut text = [b'0'; 2 + FRACTION_DIGITS as usize];
+    text[1] = b'.';
+    let text = &mut text[..2 + usize::from(digits)];
+    let mut value = value;
+    for b in text[2..].iter_mut().rev() {
+        let (rest, digit) = (value / 10, value % 10);
+        *b = b'0' + digit as u8;
+        value = rest;
+    }
+    core::str::from_utf8(text)
+        .ok()
+        .and_then(|text| text.parse().ok())
+        .unwrap_or(f32::NAN)
+}
+
+fn write_fraction(fraction: f32, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+    let digits = layout.fraction_digits;
+    if digits == 0 {
+        return fmt_fraction(fraction, f);
+    }
+    if digits <= FRACTION_DIGITS && read_fraction(layout.fraction, digits) == fraction {
+        return write!(f, ".{:01$}", layout.fraction, usize::from(digits));
+    }
+
+    let shown = digits.min(FRACTION_DIGITS);
+    let scale = 10u64.pow(shown.into());
+    let value = ((f64::from(fraction) * scale as f64 + 0.5) as u64).min(scale - 1);
+    write!(f, ".{:01$}", value, usize::from(shown))?;
+    for _ in shown..digits {
+        f.write_str("0")?;
+    }
+    Ok(())
+}
+
+fn write_timezone(timezone: i16, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+    let (hours, minutes) = (timezone.unsigned_abs() / 60, timezone.unsigned_abs() % 60);
+    let sep = separator(layout.formats.timezone, ":");
+    let written = match layout.timezone {
+        Some(Timezone::Z) | None if timezone == 0 => return f.write_str("Z"),
+        Some(Timezone::H(sign)) if minutes == 0 => {
+            return write!(
+                f,
+                "{}{:02}",
+                self::sign(Some(sign), timezone, true).unwrap(),
+                hours
+            )
+        }
+        Some(Timezone::H(sign)) | Some(Timezone::HM(sign)) => Some(sign),
+        _ => None,
+    };
+    write!(
+        f,
+        "{}{:02}{}{:02}",
+        sign(written, timezone, true).unwrap(),
+        hours,
+        sep,
+        minutes
+    )
+}
+
+impl DatePart for YmdDate {
+    fn write_date(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        write_year(self.year, 4, layout, f)?;
+        let sep = separator(layout.formats.date, "-");
+        write!(f, "{}{:02}{}{:02}", sep, self.month, sep, self.day)
+    }
+}
+
+impl DatePart for YmDate {
+    fn write_date(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        write_year(self.year, 4, layout, f)?;
+        write!(
+            f,
+            "{}{:02}",
+            separator(layout.formats.date, "-"),
+            self.month
+        )
+    }
+}
+
+impl DatePart for YDate {
+    fn write_date(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        write_year(self.year, 4, layout, f)
+    }
+}
+
+impl DatePart for CDate {
+    fn write_date(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        write_year(self.century.into(), 2, layout, f)
+    }
+}
+
+impl DatePart for WdDate {
+    fn write_date(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        write_year(self.year, 4, layout, f)?;
+        let sep = separator(layout.formats.date, "-");
+        write!(f, "{}W{:02}{}{}", sep, self.week, sep, self.day)
+    }
+}
+
+impl DatePart for WDate {
+    fn write_date(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        write_year(self.year, 4, layout, f)?;
+        write!(
+            f,
+            "{}W{:02}",
+            separator(layout.formats.date, "-"),
+            self.week
+        )
+    }
+}
+
+impl DatePart for ODate {
+    fn write_date(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        write_year(self.year, 4, layout, f)?;
+        write!(f, "{}{:03}", separator(layout.formats.date, "-"), self.day)
+    }
+}
+
+impl DatePart for Date {
+    fn write_date(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Date::YMD(date) => date.write_date(layout, f),
+            Date::WD(date) => date.write_date(layout, f),
+            Date::O(date) => date.write_date(layout, f),
+        }
+    }
+}
+
+impl DatePart for ApproxDate {
+    fn write_date(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxDate::YMD(date) => date.write_date(layout, f),
+            ApproxDate::YM(date) => date.write_date(layout, f),
+            ApproxDate::Y(date) => date.write_date(layout, f),
+            ApproxDate::C(date) => date.write_date(layout, f),
+            ApproxDate::WD(date) => date.write_date(layout, f),
+            ApproxDate::W(date) => date.write_date(layout, f),
+            ApproxDate::O(date) => date.write_date(layout, f),
+        }
+    }
+}
+
+impl TimePart for HmsTime {
+    fn write_time(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        let sep = separator(layout.formats.time, ":");
+        write!(
+            f,
+            "{:02}{}{:02}{}{:02}",
+            self.hour, sep, self.minute, sep, self.second
+        )
+    }
+}
+
+impl TimePart for HmTime {
+    fn write_time(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        let sep = separator(layout.formats.time, ":");
+        write!(f, "{:02}{}{:02}", self.hour, sep, self.minute)
+    }
+}
+
+impl TimePart for HTime {
+    fn write_time(&self, _: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}", self.hour)
+    }
+}
+
+impl TimePart for ApproxNaiveTime {
+    fn write_time(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxNaiveTime::HMS(time) => time.write_time(layout, f),
+            ApproxNaiveTime::HM(time) => time.write_time(layout, f),
+            ApproxNaiveTime::H(time) => time.write_time(layout, f),
+        }
+    }
+}
+
+impl<N> TimePart for LocalTime<N>
+where
+    N: NaiveTime + TimePart,
+{
+    fn write_time(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        self.naive.write_time(layout, f)?;
+        write_fraction(self.fraction, layout, f)
+    }
+}
+
+impl<N> TimePart for GlobalTime<N>
+where
+    N: NaiveTime + TimePart,
+{
+    fn write_time(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        self.local.write_time(layout, f)?;
+        write_timezone(self.timezone, layout, f)
+    }
+}
+
+impl<N> TimePart for AnyTime<N>
+where
+    N: NaiveTime + TimePart,
+{
+    fn write_time(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnyTime::Global(time) => time.write_time(layout, f),
+            AnyTime::Local(time) => time.write_time(layout, f),
+        }
+    }
+}
+
+impl TimePart for ApproxLocalTime {
+    fn write_time(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxLocalTime::HMS(time) => time.write_time(layout, f),
+            ApproxLocalTime::HM(time) => time.write_time(layout, f),
+            ApproxLocalTime::H(time) => time.write_time(layout, f),
+        }
+    }
+}
+
+impl TimePart for ApproxGlobalTime {
+    fn write_time(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxGlobalTime::HMS(time) => time.write_time(layout, f),
+            ApproxGlobalTime::HM(time) => time.write_time(layout, f),
+            ApproxGlobalTime::H(time) => time.write_time(layout, f),
+        }
+    }
+}
+
+impl TimePart for ApproxAnyTime {
+    fn write_time(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxAnyTime::HMS(time) => time.write_time(layout, f),
+            ApproxAnyTime::HM(time) => time.write_time(layout, f),
+            ApproxAnyTime::H(time) => time.write_time(layout, f),
+        }
+    }
+}
+
+fn write_designator(layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+    if layout.designator {
+        f.write_str("T")?;
+    }
+    Ok(())
+}
+
+macro_rules! impl_date_expression {
+    ($($ty:ty),*) => {
+        $(
+            impl Expression for $ty {
+                fn has_date(&self) -> bool {
+                    true
+                }
+
+                fn write(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+                    self.write_date(layout, f)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_time_expression {
+    ($($ty:ty),*) => {
+        $(
+            impl Expression for $ty {
+                fn has_date(&self) -> bool {
+                    false
+                }
+
+                fn write(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+                    write_designator(layout, f)?;
+                    self.write_time(layout, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_date_expression!(YmdDate, YmDate, YDate, CDate, WdDate, WDate, ODate, Date, ApproxDate);
+impl_time_expression!(
+    HmsTime,
+    HmTime,
+    HTime,
+    ApproxNaiveTime,
+    LocalTime<HmsTime>,
+    LocalTime<HmTime>,
+    LocalTime<HTime>,
+    GlobalTime<HmsTime>,
+    GlobalTime<HmTime>,
+    GlobalTime<HTime>,
+    AnyTime<HmsTime>,
+    AnyTime<HmTime>,
+    AnyTime<HTime>,
+    ApproxLocalTime,
+    ApproxGlobalTime,
+    ApproxAnyTime
+);
+
+impl<D, T> Expression for DateTime<D, T>
+where
+    D: Datelike + DatePart,
+    T: Timelike + TimePart,
+{
+    fn has_date(&self) -> bool {
+        true
+    }
+
+    fn write(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        self.date.write_date(layout, f)?;
+        write_designator(layout, f)?;
+        self.time.write_time(layout, f)
+    }
+}
+
+impl<D, T> Expression for PartialDateTime<D, T>
+where
+    D: Datelike + DatePart,
+    T: Timelike + TimePart,
+{
+    fn has_date(&self) -> bool {
+        !matches!(self, PartialDateTime::Time(_))
+    }
+
+    fn write(&self, layout: &Layout, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartialDateTime::Date(date) => date.write_date(layout, f),
+            PartialDateTime::Time(time) => {
+                write_designator(layout, f)?;
+                time.write_time(layout, f)
+            }
+            PartialDateTime::DateTime(dt) => dt.write(layout, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(i: &str) -> Parsed<PartialDateTime> {
+        i.parse().unwrap()
+    }
+
+    #[test]
+    fn layout() {
+        assert_eq!(
+            parsed("\u{2212}0044-03-15T12:30:00.250\u{2212}05").layout,
+            Layout {
+                formats: Formats {
+                    date: Some(Format::Extended),
+                    time: Some(Format::Extended),
+                    timezone: None,
+                },
+                designator: true,
+                year_sign: Some(Sign::Minus),
+                fraction_digits: 3,
+                fraction: 250,
+                timezone: Some(Timezone::H(Sign::Minus)),
+            }
+        );
+        assert_eq!(
+            parsed("T134202+0000").layout,
+            Layout {
+                formats: Formats {
+                    date: None,
+                    time: Some(Format::Basic),
+                    timezone: Some(Format::Basic),
+                },
+                designator: true,
+                year_sign: None,
+                fraction_digits: 0,
+                fraction: 0,
+                timezone: Some(Timezone::HM(Sign::Plus)),
+            }
+        );
+        assert_eq!(parsed("+2018-W31").layout.year_sign, Some(Sign::Plus));
+        assert_eq!(parsed("T13Z").layout.timezone, Some(Timezone::Z));
+    }
+
+    #[test]
+    fn reproduce() {
+        for i in &[
+            "2018",
+            "+2018",
+            "-0000",
+            "20",
+            "\u{2010}20",
+            "201808",
+            "2018-08",
+            "2018-08-02",
+            "20180802",
+            "2018-W31-4",
+            "2018W314",
+            "2018-W31",
+            "2018-214",
+            "2018214",
+            "T13",
+            "13:42",
+            "T1342",
+            "13:42:02.0",
+            "T134202.5",
+            "13:42:02.123456",
+            "13:42:02.123456789",
+            "13:42:02.99999999",
+            "13:42:02.9999999999999999999",
+            "13:42:02.0000000000000000001",
+            "2018-08-02T13:42:02Z",
+            "2018-08-02T13:42:02.5+00:00",
+            "2018-08-02T13:42:02-00",
+            "20180802T134202.000\u{2212}0530",
+            "20180802T13:42:02+02",
+            "2018-W31-4T13.5Z",
+        ] {
+            assert_eq!(parsed(i).to_string(), *i);
+        }
+        assert_eq!(
+            "20180802T134202Z"
+                .parse::<Parsed<DateTime<Date, GlobalTime>>>()
+                .unwrap()
+                .to_string(),
+            "20180802T134202Z"
+        );
+        assert_eq!(
+            "13:42:02.50 and more"
+                .parse::<Parsed<ApproxAnyTime>>()
+                .unwrap()
+                .to_string(),
+            "13:42:02.50"
+        );
+    }
+
+    #[test]
+    fn changed() {
+        let mut parsed: Parsed<DateTime<Date, GlobalTime>> =
+            "+2018-08-02T13:42:02+02".parse().unwrap();
+        parsed.value.date = Date::YMD(YmdDate {
+            year: -44,
+            month: 3,
+            day: 15,
+        });
+        parsed.value.time.timezone = -330;
+        parsed.value.time.local.fraction = 0.5;
+        assert_eq!(parsed.to_string(), "-0044-03-15T13:42:02.5-05:30");
+
+        let mut parsed: Parsed<LocalTime> = "13:42:02.123456789".parse().unwrap();
+        parsed.value.fraction = 0.5;
+        assert_eq!(parsed.to_string(), "13:42:02.500000000");
+        parsed.value.fraction = 1.;
+        assert_eq!(parsed.to_string(), "13:42:02.999999999");
+
+        let mut parsed: Parsed<LocalTime> = "13:42:02.0000000000000000000001".parse().unwrap();
+        parsed.value.fraction = 0.5;
+        assert_eq!(parsed.to_string(), "13:42:02.5000000000000000000000");
+    }
+
+    #[test]
+    fn streaming() {
+        assert_eq!(
+            Parsed::<Date>::parse_streaming("2018-08-02"),
+            Err(Error::Incomplete)
+        );
+        let (rest, parsed) = Parsed::<Date>::parse_streaming("\u{2212}0044-03-15 BC").unwrap();
+        assert_eq!(rest, " BC");
+        assert_eq!(parsed.to_string(), "\u{2212}0044-03-15");
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{date::*, Valid};
+use std::{
+    iter::FromIterator,
+    str::FromStr,
+    sync::{Arc, RwLock},
+};
+
+/// Days from 1900-01-01, the NTP epoch, to 1970-01-01.
+const NTP_EPOCH_DAYS: i64 = -25_567;
+
+static TABLE: RwLock<Option<Arc<LeapSeconds>>> = RwLock::new(None);
+
+/// Positive leap seconds, each identified by the UTC day
+/// at whose end `23:59:60` was inserted.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct LeapSeconds {
+    /// Sorted days since 1970-01-01.
+    days: Vec<i64>,
+}
+
+impl LeapSeconds {
+    /// The leap seconds announced by the IERS up to the release of this crate.
+    pub fn iers() -> Self {
+        include_str!("leap-seconds.list")
+            .parse()
+            .expect("embedded leap second list")
+    }
+
+    /// Whether a leap second was inserted at the end of the given UTC day.
+    pub fn contains(&self, date: &Date) -> bool {
+        date.is_valid()
+            && self
+                .days
+                .binary_search(&ODate::from(date.clone()).days_since_epoch())
+                .is_ok()
+    }
+
+    /// The table used for validation, [`LeapSeconds::iers`] unless replaced.
+    pub fn current() -> Arc<Self> {
+        if let Some(table) = TABLE.read().unwrap().as_ref() {
+            return table.clone();
+        }
+        TABLE
+            .write()
+            .unwrap()
+            .get_or_insert_with(|| Arc::new(Self::iers()))
+            .clone()
+    }
+
+    /// Replaces the table used for validation,
+    /// for example with a newer list than the one embedded.
+    pub fn set_current(table: Self) {
+        *TABLE.write().unwrap() = Some(Arc::new(table));
+    }
+}
+
+/// Parses a `leap-seconds.list` file as published by the IERS
+/// and shipped with the IANA time zone database.
+impl FromStr for LeapSeconds {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days = Vec::new();
+        let mut last_offset = None;
+        for line in s.lines() {
+            let line = line.split('#').next().unwrap_or_default();
+            let mut fields = line.split_whitespace();
+            let (ntp, offset) = match (fields.next(), fields.next()) {
+                (None, _) => continue,
+                (Some(ntp), Some(offset)) => (
+                    ntp.parse::<i64>().or(Err(()))?,
+                    offset.parse::<i64>().or(Err(()))?,
+                ),
+                (Some(_), None) => return Err(()),
+            };
+
+            match last_offset {
+                Some(last) if offset == last + 1 => {
+                    days.push(ntp.div_euclid(24 * 60 * 60) + NTP_EPOCH_DAYS - 1)
+                }
+                // Negative leap seconds have never been announced.
+                Some(_) => return Err(()),
+                None => {}
+            }
+            last_offset = Some(offset);
+        }
+        Ok(Self { days })
+    }
+}
+
+/// Collects the days at whose end a leap second was inserted.
+/// Dates that do not exist are skipped.
+impl FromIterator<Date> for LeapSeconds {
+    fn from_iter<I: IntoIterator<Item = Date>>(dates: I) -> Self {
+        let mut days = dates
+            .into_iter()
+            .filter(Date::is_valid)
+            .map(|date| ODate::from(date).days_since_epoch())
+            .collect::<Vec<_>>();
+        days.sort_unstable();
+        days.dedup();
+        Self { days }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i16, month: u8, day: u8) -> Date {
+        Date::YMD(YmdDate { year, month, day })
+    }
+
+    #[test]
+    fn iers() {
+        let table = LeapSeconds::iers();
+        assert_eq!(table.days.len(), 27);
+        assert!(table.contains(&ymd(1972, 6, 30)));
+        assert!(table.contains(&ymd(2016, 12, 31)));
+        assert!(table.contains(&Date::O(ODate {
+            year: 2016,
+            day: 366
+        })));
+        assert!(!table.contains(&ymd(1971, 12, 31)));
+        assert!(!table.contains(&ymd(2017, 1, 1)));
+        assert!(!table.contains(&ymd(2018, 12, 31)));
+        assert!(!table.contains(&ymd(2016, 13, 31)));
+    }
+
+    #[test]
+    fn from_iter() {
+        let table: LeapSeconds = vec![ymd(2016, 12, 31), ymd(1972, 6, 30), ymd(2016, 12, 31)]
+            .into_iter()
+            .chain(Some(ymd(2018, 2, 30)))
+            .collect();
+        assert_eq!(table.days.len(), 2);
+        assert!(table.contains(&ymd(1972, 6, 30)));
+        assert!(table.contains(&ymd(2016, 12, 31)));
+    }
+
+    #[test]
+    fn from_str() {
+        let list = "# comment\n\
+                    2272060800 10 # 1 Jan 1972\n\
+                    \n\
+                    2287785600 11 # 1 Jul 1972\n";
+        assert_eq!(
+            list.parse::<LeapSeconds>(),
+            Ok(vec![ymd(1972, 6, 30)].into_iter().collect())
+        );
+        assert_eq!("2272060800".parse::<LeapSeconds>(), Err(()));
+        assert_eq!("2272060800 ten".parse::<LeapSeconds>(), Err(()));
+        assert_eq!(
+            "2272060800 10\n2287785600 9".parse::<LeapSeconds>(),
+            Err(())
+        );
+    }
+}
@@ -1,3 +1,5 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
 mod test_readme {
     #[doc = include_str!("../README.md")]
     #[cfg(doctest)]
@@ -6,13 +8,29 @@ mod test_readme {
 
 macro_rules! impl_fromstr_parse {
     ($ty:ty, $func:ident) => {
-        impl ::std::str::FromStr for $ty {
+        impl ::core::str::FromStr for $ty {
             type Err = ();
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
                 crate::parse::$func(s.as_bytes()).map(|x| x.1).or(Err(()))
             }
         }
+
+        impl crate::input::Parse for $ty {
+            fn parse_complete<'a, I>(i: I) -> Result<(I, Self), crate::input::Error>
+            where
+                I: crate::input::Input<'a>,
+            {
+                crate::input::complete(i, crate::parse::$func)
+            }
+
+            fn parse_streaming<'a, I>(i: I) -> Result<(I, Self), crate::input::Error>
+            where
+                I: crate::input::Input<'a>,
+            {
+                crate::input::streaming(i, crate::parse::$func)
+            }
+        }
     };
 }
 
@@ -20,10 +38,36 @@ macro_rules! impl_fromstr_parse {
 pub mod chrono;
 mod date;
 mod datetime;
+mod find;
+mod format;
+pub mod input;
+#[cfg(feature = "jiff")]
+pub mod jiff;
+pub mod layout;
+#[cfg(feature = "leap-seconds")]
+mod leap_second;
 mod parse;
+pub mod parsers;
+pub mod profile;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod sign;
 mod time;
+/// Conversions to and from the `time` crate,
+/// named so as not to clash with this crate's own `time` module.
+#[cfg(feature = "time")]
+pub mod time_crate;
 
-pub use crate::{date::*, datetime::*, time::*};
+#[cfg(feature = "leap-seconds")]
+pub use crate::leap_second::*;
+pub use crate::{
+    date::*,
+    datetime::*,
+    find::*,
+    format::{Format, Formats},
+    sign::*,
+    time::*,
+};
 
 pub trait Valid {
     fn is_valid(&self) -> bool;
@@ -16,7 +16,7 @@ fn positive_century(i: &[u8]) -> IResult<&[u8], u8> {
 fn century(i: &[u8]) -> IResult<&[u8], i8> {
     let (i, sign) = opt(sign)(i)?;
     let (i, century) = positive_century(i)?;
-    Ok((i, sign.unwrap_or(1) * century as i8))
+    Ok((i, sign.map_or(1, Sign::signum) * century as i8))
 }
 
 // TODO support expanded year
@@ -27,7 +27,7 @@ fn positive_year(i: &[u8]) -> IResult<&[u8], u16> {
 fn year(i: &[u8]) -> IResult<&[u8], i16> {
     let (i, sign) = opt(sign)(i)?;
     let (i, year) = positive_year(i)?;
-    Ok((i, sign.unwrap_or(1) as i16 * year as i16))
+    Ok((i, sign.map_or(1, Sign::signum) as i16 * year as i16))
 }
 
 fn month(i: &[u8]) -> IResult<&[u8], u8> {
@@ -42,7 +42,7 @@ fn year_week(i: &[u8]) -> IResult<&[u8], u8> {
     map(take_while_m_n(2, 2, is_digit), buf_to_int)(i)
 }
 
-fn year_day(i: &[u8]) -> IResult<&[u8], u8> {
+fn year_day(i: &[u8]) -> IResult<&[u8], u16> {
     map(take_while_m_n(3, 3, is_digit), buf_to_int)(i)
 }
 
@@ -93,13 +93,7 @@ fn date_o_format(i: &[u8], extended: bool) -> IResult<&[u8], ODate> {
     let (i, year) = year(i)?;
     let (i, _) = cond(extended, char('-'))(i)?;
     let (i, day) = year_day(i)?;
-    Ok((
-        i,
-        ODate {
-            year,
-            day: day.into(),
-        },
-    ))
+    Ok((i, ODate { year, day }))
 }
 fn date_o_basic(i: &[u8]) -> IResult<&[u8], ODate> {
     date_o_format(i, false)
@@ -175,6 +169,24 @@ pub fn date_approx(i: &[u8]) -> IResult<&[u8], ApproxDate> {
     ))(i)
 }
 
+/// Any complete or reduced accuracy date in one format only.
+/// Years, centuries and `2018-08` read the same in both (4.1.2.3a).
+pub fn date_approx_format(i: &[u8], format: Format) -> IResult<&[u8], ApproxDate> {
+    let extended = format == Format::Extended;
+    alt((
+        complete(map(move |i| date_wd_format(i, extended), ApproxDate::WD)),
+        complete(map(move |i| date_ymd_format(i, extended), ApproxDate::YMD)),
+        complete(map(move |i| date_o_format(i, extended), ApproxDate::O)),
+        complete(map(move |i| date_w_format(i, extended), ApproxDate::W)),
+        complete(map(
+            if extended { date_ym_extended } else { date_ym },
+            ApproxDate::YM,
+        )),
+        complete(map(date_y, ApproxDate::Y)),
+        complete(map(date_c, ApproxDate::C)),
+    ))(i)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,6 +201,9 @@ mod tests {
         assert_eq!(super::year(b"2018"), Ok((&[][..], 2018)));
         assert_eq!(super::year(b"+2018"), Ok((&[][..], 2018)));
         assert_eq!(super::year(b"-2018"), Ok((&[][..], -2018)));
+        assert_eq!(super::year("\u{2212}0044".as_bytes()), Ok((&[][..], -44)));
+        assert_eq!(super::year("\u{2010}0044".as_bytes()), Ok((&[][..], -44)));
+        assert!(super::year(&"\u{2212}0044".as_bytes()[1..]).is_err());
     }
 
     #[test]
@@ -208,6 +223,8 @@ mod tests {
         assert_eq!(super::year_day(b"011"), Ok((&[][..], 11)));
         assert_eq!(super::year_day(b"111"), Ok((&[][..], 111)));
         assert_eq!(super::year_day(b"1111"), Ok((&b"1"[..], 111)));
+        assert_eq!(super::year_day(b"400"), Ok((&[][..], 400)));
+        assert_eq!(super::year_day(b"999"), Ok((&[][..], 999)));
     }
 
     #[test]
@@ -249,6 +266,17 @@ mod tests {
             );
             assert_eq!(super::date_ymd(b"-03330611"), Ok((&[][..], value)));
         }
+        assert_eq!(
+            super::date_ymd("\u{2212}0044-03-15".as_bytes()),
+            Ok((
+                &[][..],
+                YmdDate {
+                    year: -44,
+                    month: 3,
+                    day: 15,
+                }
+            ))
+        );
         assert_eq!(
             super::date_ymd(b"2016-02-29"),
             Ok((
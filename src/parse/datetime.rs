@@ -6,11 +6,10 @@ use nom::{
     combinator::{complete, cond, opt},
     IResult,
 };
-use nom_regex::{bytes::re_match, lib::regex};
 
 macro_rules! datetime {
-    (pub $name:ident, $date:ty, $date_parser:ident, $time:ty, $time_parser:ident) => {
-        pub fn $name(i: &[u8]) -> IResult<&[u8], DateTime<$date, $time>> {
+    ($vis:vis $name:ident, $date:ty, $date_parser:ident, $time:ty, $time_parser:ident) => {
+        $vis fn $name(i: &[u8]) -> IResult<&[u8], DateTime<$date, $time>> {
             let (i, date) = $date_parser(i)?;
             let (i, _) = char('T')(i)?;
             let (i, _) = peek(not(char('T')))(i)?;
@@ -19,13 +18,13 @@ macro_rules! datetime {
         }
     };
 }
-datetime!(pub datetime_global_hms,           Date,       date,        GlobalTime<HmsTime>, time_global_hms);
+datetime!(pub datetime_global_hms_general,   Date,       date,        GlobalTime<HmsTime>, time_global_hms);
 datetime!(pub datetime_global_hm,            Date,       date,        GlobalTime<HmTime>,  time_global_hm);
 datetime!(pub datetime_global_h,             Date,       date,        GlobalTime<HTime>,   time_global_h);
 datetime!(pub datetime_local_hms,            Date,       date,        LocalTime<HmsTime>,  time_local_hms);
 datetime!(pub datetime_local_hm,             Date,       date,        LocalTime<HmTime>,   time_local_hm);
 datetime!(pub datetime_local_h,              Date,       date,        LocalTime<HTime>,    time_local_h);
-datetime!(pub datetime_any_hms,              Date,       date,        AnyTime<HmsTime>,    time_any_hms);
+datetime!(pub datetime_any_hms_general,      Date,       date,        AnyTime<HmsTime>,    time_any_hms);
 datetime!(pub datetime_any_hm,               Date,       date,        AnyTime<HmTime>,     time_any_hm);
 datetime!(pub datetime_any_h,                Date,       date,        AnyTime<HTime>,      time_any_h);
 datetime!(pub datetime_global_approx,        Date,       date,        ApproxGlobalTime,    time_global_approx);
@@ -44,15 +43,199 @@ datetime!(pub datetime_approx_global_approx, ApproxDate, date_approx, ApproxGlob
 datetime!(pub datetime_approx_local_approx,  ApproxDate, date_approx, ApproxLocalTime,     time_local_approx);
 datetime!(pub datetime_approx_any_approx,    ApproxDate, date_approx, ApproxAnyTime,       time_any_approx);
 
+/// Calendar date and time of day in extended format
+/// with an optional decimal fraction and a time zone of `Z` or `±hh:mm`,
+/// as in RFC 3339: `YYYY-MM-DDThh:mm:ss[.f]Z`.
+///
+/// Checks the fixed positions at once instead of trying alternatives.
+/// `None` for anything else, including valid expressions in other shapes.
+///
+/// With `relaxed`, also accepts `t` or a space instead of `T`
+/// and `z` instead of `Z` like RFC 3339 does (5.6),
+/// which ISO 8601 does not.
+pub(crate) fn rfc3339(i: &[u8], relaxed: bool) -> Option<(&[u8], DateTime<Date, GlobalTime>)> {
+    const DIGITS: [usize; 14] = [0, 1, 2, 3, 5, 6, 8, 9, 11, 12, 14, 15, 17, 18];
+
+    let layout = i.get(..19)?;
+    let mut d = [0; 14];
+    let designator = match layout[10] {
+        b't' | b' ' if relaxed => b'T',
+        designator => designator,
+    };
+    let mut invalid = layout[4] ^ b'-'
+        | layout[7] ^ b'-'
+        | designator ^ b'T'
+        | layout[13] ^ b':'
+        | layout[16] ^ b':';
+    for (d, &pos) in d.iter_mut().zip(DIGITS.iter()) {
+        *d = layout[pos].wrapping_sub(b'0');
+        invalid |= (*d > 9) as u8;
+    }
+    if invalid != 0 {
+        return None;
+    }
+
+    let two = |n: usize| d[n] * 10 + d[n + 1];
+    let date = YmdDate {
+        year: (two(0) as i16) * 100 + two(2) as i16,
+        month: two(4),
+        day: two(6),
+    };
+    let naive = HmsTime {
+        hour: two(8),
+        minute: two(10),
+        second: two(12),
+    };
+
+    let mut i = &i[19..];
+    let mut fraction = 0.;
+    if i.first() == Some(&b'.') {
+        let len = 1 + i[1..].iter().take_while(|b| b.is_ascii_digit()).count();
+        if len == 1 || matches!(i.get(len), Some(b'e') | Some(b'E')) {
+            return None;
+        }
+        fraction = core::str::from_utf8(&i[..len]).ok()?.parse().ok()?;
+        i = &i[len..];
+    }
+
+    let timezone = match *i.first()? {
+        b'Z' | b'z' if relaxed || i[0] == b'Z' => {
+            i = &i[1..];
+            0
+        }
+        sign @ b'+' | sign @ b'-' => {
+            let offset = i.get(1..6)?;
+            let h = [offset[0], offset[1]].map(|b| b.wrapping_sub(b'0'));
+            let m = [offset[3], offset[4]].map(|b| b.wrapping_sub(b'0'));
+            if offset[2] != b':' || h.iter().chain(m.iter()).any(|&d| d > 9) {
+                return None;
+            }
+            i = &i[6..];
+            let minutes = (h[0] * 10 + h[1]) as i16 * 60 + (m[0] * 10 + m[1]) as i16;
+            if sign == b'-' {
+                -minutes
+            } else {
+                minutes
+            }
+        }
+        _ => return None,
+    };
+
+    Some((
+        i,
+        DateTime {
+            date: Date::YMD(date),
+            time: GlobalTime {
+                local: LocalTime { naive, fraction },
+                timezone,
+            },
+        },
+    ))
+}
+
+pub fn datetime_global_hms(i: &[u8]) -> IResult<&[u8], DateTime<Date, GlobalTime<HmsTime>>> {
+    match rfc3339(i, false) {
+        Some(parsed) => Ok(parsed),
+        None => datetime_global_hms_general(i),
+    }
+}
+
+pub fn datetime_any_hms(i: &[u8]) -> IResult<&[u8], DateTime<Date, AnyTime<HmsTime>>> {
+    match rfc3339(i, false) {
+        Some((i, DateTime { date, time })) => Ok((
+            i,
+            DateTime {
+                date,
+                time: AnyTime::Global(time),
+            },
+        )),
+        None => datetime_any_hms_general(i),
+    }
+}
+
+/// Whether an expression starts with a date:
+/// either a time designator follows something on the same line,
+/// or there is neither a time designator nor a time separator.
+///
+/// Only the bytes `T`, `:` and `\n` are special.
+/// Any other byte, even one that is not UTF-8, counts as something.
+fn has_date(i: &[u8]) -> bool {
+    if i.contains(&b'T') {
+        i.len() > 1 && i[1..].contains(&b'T') && !i.contains(&b'\n')
+    } else {
+        !i.contains(&b':')
+    }
+}
+
 pub fn partial_datetime_approx_any_approx(
     i: &[u8],
 ) -> IResult<&[u8], PartialDateTime<ApproxDate, ApproxAnyTime>> {
-    let re = regex::bytes::Regex::new(r"^(.+T.*|[^T:]*)$").unwrap();
-    let (i, has_date) = opt(peek(re_match(re)))(i)?;
-    let (i, date) = cond(has_date.is_some(), date_approx)(i)?;
+    partial_datetime(i, has_date(i), date_approx, time_any_approx)
+}
+
+/// A date, a time or both in one format only.
+pub fn partial_datetime_format(
+    i: &[u8],
+    format: Format,
+) -> IResult<&[u8], PartialDateTime<ApproxDate, ApproxAnyTime>> {
+    partial_datetime(
+        i,
+        has_date(i),
+        move |i| date_approx_format(i, format),
+        move |i| time_any_approx_format(i, format),
+    )
+}
+
+/// A date, a time or both with the format each part was written in.
+pub fn partial_datetime_formats(
+    i: &[u8],
+) -> IResult<&[u8], (PartialDateTime<ApproxDate, ApproxAnyTime>, Formats)> {
+    let (i, (value, formats, _)) = partial_datetime_formats_of(i, has_date(i))?;
+    Ok((i, (value, formats)))
+}
+
+/// Like `partial_datetime_formats` for input known to start with a date or not.
+/// Also returns the text after the date, which is all of it without a date.
+#[allow(clippy::type_complexity)]
+pub fn partial_datetime_formats_of(
+    i: &[u8],
+    date: bool,
+) -> IResult<&[u8], (PartialDateTime<ApproxDate, ApproxAnyTime>, Formats, &[u8])> {
+    let mut date_format = None;
+    let mut time_text = i;
+    let mut time_formats = Formats::default();
+    let (i, value) = partial_datetime(
+        i,
+        date,
+        |i| {
+            let (i, (date, format)) = formatted(i, date_approx_format)?;
+            date_format = format;
+            time_text = i;
+            Ok((i, date))
+        },
+        |i| {
+            let (i, (time, formats)) = time_any_approx_formats(i)?;
+            time_formats = formats;
+            Ok((i, time))
+        },
+    )?;
+    let formats = Formats {
+        date: date_format,
+        ..time_formats
+    };
+    Ok((i, (value, formats, time_text)))
+}
+
+fn partial_datetime<'a>(
+    i: &'a [u8],
+    has_date: bool,
+    date: impl FnMut(&'a [u8]) -> IResult<&'a [u8], ApproxDate>,
+    time: impl FnMut(&'a [u8]) -> IResult<&'a [u8], ApproxAnyTime>,
+) -> IResult<&'a [u8], PartialDateTime<ApproxDate, ApproxAnyTime>> {
+    let (i, date) = cond(has_date, date)(i)?;
     let (i, _) = opt(complete(char('T')))(i)?;
     let (i, _) = opt(complete(peek(not(char('T')))))(i)?;
-    let (i, time) = opt(time_any_approx)(i)?;
+    let (i, time) = opt(time)(i)?;
     Ok((
         i,
         match (date, time) {
@@ -299,4 +482,148 @@ mod tests {
             Ok((&[][..], result))
         );
     }
+
+    #[test]
+    fn has_date() {
+        assert!(super::has_date(b"2018"));
+        assert!(super::has_date(b"2018-08-02T12:30"));
+        assert!(super::has_date(b"T12T"));
+        assert!(!super::has_date(b"12:30"));
+        assert!(!super::has_date(b"T12"));
+        assert!(!super::has_date(b"2018\nT12"));
+
+        assert!(super::has_date(b"2018-08-02\xff"));
+        assert!(super::has_date(b"2018-08-02T\xffT12"));
+        assert!(!super::has_date(b"12:30\xff"));
+        assert!(super::has_date(b"\xffT12"));
+        assert_eq!(
+            partial_datetime_approx_any_approx(b"2018\xff"),
+            Ok((
+                &b"\xff"[..],
+                PartialDateTime::Date(ApproxDate::Y(YDate { year: 2018 }))
+            ))
+        );
+    }
+
+    #[test]
+    fn rfc3339() {
+        let mut inputs = vec![];
+        for date in &["2018-08-02", "0000-01-01", "9999-12-31", "2018-13-45"] {
+            for time in &["13:42:02", "00:00:00", "24:00:00", "23:59:60", "99:99:99"] {
+                for fraction in &["", ".5", ".123456789", ".", ".5e3", ".0"] {
+                    for zone in &["Z", "+02:00", "-05:30", "+0200", "+02", "+02:0", "", "z"] {
+                        for rest in &["", " ", "Z", "T"] {
+                            inputs.push(format!("{}T{}{}{}{}", date, time, fraction, zone, rest));
+                        }
+                    }
+                }
+            }
+        }
+        inputs.push("2018-08-02t13:42:02Z".into());
+        inputs.push("+2018-08-02T13:42:02Z".into());
+        inputs.push("2018-08-02T13:42:02.5\u{2212}02:00".into());
+        inputs.push("2018-0a-02T13:42:02Z".into());
+
+        let mut fast = 0;
+        for input in &inputs {
+            let input = input.as_bytes();
+            if let Some(parsed) = super::rfc3339(input, false) {
+                fast += 1;
+                assert_eq!(
+                    Ok(parsed),
+                    datetime_global_hms_general(input),
+                    "{}",
+                    String::from_utf8_lossy(input)
+                );
+            }
+            assert_eq!(
+                datetime_global_hms(input),
+                datetime_global_hms_general(input),
+                "{}",
+                String::from_utf8_lossy(input)
+            );
+            assert_eq!(
+                datetime_any_hms(input),
+                datetime_any_hms_general(input),
+                "{}",
+                String::from_utf8_lossy(input)
+            );
+        }
+        assert!(fast > inputs.len() / 10);
+        assert_eq!(
+            datetime_global_hms("2018-08-02T13:42:02\u{2212}05:00".as_bytes())
+                .map(|(_, dt)| dt.time.timezone),
+            Ok(-5 * 60)
+        );
+    }
+
+    #[test]
+    fn partial_datetime_format() {
+        use crate::Format::*;
+
+        for (input, format) in &[
+            ("20180802T134202.5-0530", Basic),
+            ("2018W314T13Z", Basic),
+            ("201808", Basic),
+            ("2018-08-02T13:42:02.5-05:30", Extended),
+            ("2018-214T13+02", Extended),
+            ("2018-08", Extended),
+            ("2018-08", Basic),
+            ("T13", Basic),
+        ] {
+            assert_eq!(
+                super::partial_datetime_format(input.as_bytes(), *format),
+                super::partial_datetime_approx_any_approx(input.as_bytes()),
+                "{}",
+                input
+            );
+        }
+        assert_eq!(
+            super::partial_datetime_format(b"20180802T13:42", Basic).map(|(rest, _)| rest),
+            Ok(&b":42"[..])
+        );
+        assert_eq!(
+            super::partial_datetime_format(b"2018-08-02T13:42+0200", Extended)
+                .map(|(rest, _)| rest),
+            Ok(&b"00"[..])
+        );
+    }
+
+    #[test]
+    fn partial_datetime_formats() {
+        use Format::*;
+
+        let formats_of = |i: &str| {
+            let (rest, (_, formats)) = super::partial_datetime_formats(i.as_bytes()).unwrap();
+            assert!(rest.is_empty(), "{}", i);
+            (formats.date, formats.time, formats.timezone)
+        };
+        assert_eq!(formats_of("2018-08-02"), (Some(Extended), None, None));
+        assert_eq!(formats_of("20180802"), (Some(Basic), None, None));
+        assert_eq!(formats_of("2018-W31"), (Some(Extended), None, None));
+        assert_eq!(formats_of("2018W31"), (Some(Basic), None, None));
+        assert_eq!(formats_of("2018214"), (Some(Basic), None, None));
+        assert_eq!(formats_of("\u{2212}2018-214"), (Some(Extended), None, None));
+        assert_eq!(formats_of("2018-08"), (None, None, None));
+        assert_eq!(formats_of("2018"), (None, None, None));
+        assert_eq!(formats_of("13:42"), (None, Some(Extended), None));
+        assert_eq!(formats_of("T1342"), (None, Some(Basic), None));
+        assert_eq!(formats_of("T13.5Z"), (None, None, None));
+        assert_eq!(
+            formats_of("2018-08-02T13:42:02.5+02:00"),
+            (Some(Extended), Some(Extended), Some(Extended))
+        );
+        assert_eq!(
+            formats_of("20180802T134202-0530"),
+            (Some(Basic), Some(Basic), Some(Basic))
+        );
+        assert_eq!(
+            formats_of("20180802T13:42:02+02"),
+            (Some(Basic), Some(Extended), None)
+        );
+        assert_eq!(
+            formats_of("2018-08-02T13\u{2212}05:00"),
+            (Some(Extended), None, Some(Extended))
+        );
+    }
 }
@@ -4,15 +4,17 @@ mod time;
 
 pub use self::{date::*, datetime::*, time::*};
 
+use crate::{Format, Formats, Sign};
+use core::ops::{AddAssign, MulAssign};
 use nom::combinator::peek;
 use nom::{
     branch::alt,
-    character::streaming::{char, one_of},
-    combinator::{map_opt, value},
+    bytes::complete::tag,
+    character::streaming::char,
+    combinator::{complete, map_opt, value},
     number::complete::recognize_float,
     IResult, ParseTo,
 };
-use std::ops::{AddAssign, MulAssign};
 
 fn buf_to_int<T>(buf: &[u8]) -> T
 where
@@ -26,8 +28,34 @@ where
     sum
 }
 
-pub fn sign(i: &[u8]) -> IResult<&[u8], i8> {
-    alt((value(-1, one_of("-\u{2212}\u{2010}")), value(1, char('+'))))(i)
+/// Matches the minus sign and hyphen as whole UTF-8 sequences
+/// rather than single bytes.
+pub fn sign(i: &[u8]) -> IResult<&[u8], Sign> {
+    alt((
+        value(Sign::Minus, complete(tag("\u{2212}"))),
+        value(Sign::Hyphen, complete(tag("\u{2010}"))),
+        value(Sign::HyphenMinus, char('-')),
+        value(Sign::Plus, char('+')),
+    ))(i)
+}
+
+/// Parses a part in both formats and keeps the longer match,
+/// or `None` for the format if the part reads the same in both.
+fn formatted<'a, T>(
+    i: &'a [u8],
+    parser: fn(&'a [u8], Format) -> IResult<&'a [u8], T>,
+) -> IResult<&'a [u8], (T, Option<Format>)> {
+    match (parser(i, Format::Extended), parser(i, Format::Basic)) {
+        (Ok((extended, value)), Ok((basic, _))) if extended.len() == basic.len() => {
+            Ok((extended, (value, None)))
+        }
+        (Ok((extended, value)), Ok((basic, _))) if extended.len() < basic.len() => {
+            Ok((extended, (value, Some(Format::Extended))))
+        }
+        (_, Ok((basic, value))) => Ok((basic, (value, Some(Format::Basic)))),
+        (Ok((extended, value)), Err(_)) => Ok((extended, (value, Some(Format::Extended)))),
+        (Err(err), Err(_)) => Err(err),
+    }
 }
 
 fn frac32(i: &[u8]) -> IResult<&[u8], f32> {
@@ -39,6 +67,7 @@ fn frac32(i: &[u8]) -> IResult<&[u8], f32> {
 #[cfg(test)]
 mod tests {
     use {
+        crate::Sign,
         nom::{
             error::{Error, ErrorKind::Char},
             Err,
@@ -49,8 +78,16 @@ mod tests {
 
     #[test]
     fn sign() {
-        assert_eq!(super::sign(b"-"), Ok((&[][..], -1)));
-        assert_eq!(super::sign(b"+"), Ok((&[][..], 1)));
+        assert_eq!(super::sign(b"-"), Ok((&[][..], Sign::HyphenMinus)));
+        assert_eq!(super::sign(b"+"), Ok((&[][..], Sign::Plus)));
+        assert_eq!(
+            super::sign("\u{2212}1".as_bytes()),
+            Ok((&b"1"[..], Sign::Minus))
+        );
+        assert_eq!(
+            super::sign("\u{2010}".as_bytes()),
+            Ok((&[][..], Sign::Hyphen))
+        );
         assert_eq!(
             super::sign(b""),
             Err(Err::Incomplete(Size(NonZeroUsize::new(1).unwrap())))
@@ -68,7 +68,7 @@ pub fn time_h(i: &[u8]) -> IResult<&[u8], HTime> {
     map(hour, |hour| HTime { hour })(i)
 }
 
-fn time_naive_approx(i: &[u8]) -> IResult<&[u8], ApproxNaiveTime> {
+pub fn time_naive_approx(i: &[u8]) -> IResult<&[u8], ApproxNaiveTime> {
     alt((
         complete(map(time_hms, ApproxNaiveTime::HMS)),
         complete(map(time_hm, ApproxNaiveTime::HM)),
@@ -180,7 +180,7 @@ fn timezone_fixed(i: &[u8]) -> IResult<&[u8], i16> {
     }))(i)?;
     Ok((
         i,
-        sign as i16 * (hour as i16 * 60 + minute.unwrap_or(0) as i16),
+        sign.signum() as i16 * (hour as i16 * 60 + minute.unwrap_or(0) as i16),
     ))
 }
 
@@ -188,6 +188,93 @@ fn timezone(i: &[u8]) -> IResult<&[u8], i16> {
     alt((timezone_utc, timezone_fixed))(i)
 }
 
+fn timezone_fixed_format(i: &[u8], extended: bool) -> IResult<&[u8], i16> {
+    let (i, sign) = sign(i)?;
+    let (i, hour) = hour(i)?;
+    let (i, minute) = opt(complete(move |i| {
+        let (i, _) = cond(extended, char(':'))(i)?;
+        minute(i)
+    }))(i)?;
+    Ok((
+        i,
+        sign.signum() as i16 * (hour as i16 * 60 + minute.unwrap_or(0) as i16),
+    ))
+}
+
+fn timezone_format(i: &[u8], format: Format) -> IResult<&[u8], i16> {
+    let extended = format == Format::Extended;
+    alt((timezone_utc, move |i| timezone_fixed_format(i, extended)))(i)
+}
+
+/// Local time of any accuracy in one format only.
+fn time_local_approx_format(i: &[u8], format: Format) -> IResult<&[u8], ApproxLocalTime> {
+    let extended = format == Format::Extended;
+    let (i, _) = opt(nom::character::complete::char('T'))(i)?;
+    let (i, naive) = alt((
+        complete(map(
+            move |i| time_hms_format(i, extended),
+            ApproxNaiveTime::HMS,
+        )),
+        complete(map(
+            move |i| time_hm_format(i, extended),
+            ApproxNaiveTime::HM,
+        )),
+        complete(map(time_h, ApproxNaiveTime::H)),
+    ))(i)?;
+    let (i, fraction) = opt(complete(frac32))(i)?;
+    let fraction = fraction.unwrap_or(0.);
+    Ok((
+        i,
+        match naive {
+            ApproxNaiveTime::HMS(naive) => ApproxLocalTime::HMS(LocalTime { naive, fraction }),
+            ApproxNaiveTime::HM(naive) => ApproxLocalTime::HM(LocalTime { naive, fraction }),
+            ApproxNaiveTime::H(naive) => ApproxLocalTime::H(LocalTime { naive, fraction }),
+        },
+    ))
+}
+
+fn any<N: NaiveTime>(local: LocalTime<N>, timezone: Option<i16>) -> AnyTime<N> {
+    match timezone {
+        Some(timezone) => AnyTime::Global(GlobalTime { local, timezone }),
+        None => AnyTime::Local(local),
+    }
+}
+
+fn any_approx(local: ApproxLocalTime, timezone: Option<i16>) -> ApproxAnyTime {
+    match local {
+        ApproxLocalTime::HMS(local) => ApproxAnyTime::HMS(any(local, timezone)),
+        ApproxLocalTime::HM(local) => ApproxAnyTime::HM(any(local, timezone)),
+        ApproxLocalTime::H(local) => ApproxAnyTime::H(any(local, timezone)),
+    }
+}
+
+/// Local or global time of any accuracy in one format only.
+/// Hours, `Z` and `±hh` read the same in both.
+pub fn time_any_approx_format(i: &[u8], format: Format) -> IResult<&[u8], ApproxAnyTime> {
+    let (i, local) = time_local_approx_format(i, format)?;
+    let (i, timezone) = opt(complete(move |i| timezone_format(i, format)))(i)?;
+    Ok((i, any_approx(local, timezone)))
+}
+
+/// Local or global time of any accuracy
+/// with the formats of the time and of the time zone.
+pub fn time_any_approx_formats(i: &[u8]) -> IResult<&[u8], (ApproxAnyTime, Formats)> {
+    let (i, (local, time)) = formatted(i, time_local_approx_format)?;
+    let (i, timezone) = opt(complete(|i| formatted(i, timezone_format)))(i)?;
+    let formats = Formats {
+        date: None,
+        time,
+        timezone: timezone.and_then(|(_, format)| format),
+    };
+    Ok((
+        i,
+        (
+            any_approx(local, timezone.map(|(timezone, _)| timezone)),
+            formats,
+        ),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,6 +343,14 @@ mod tests {
         assert_eq!(super::timezone(b"-2211"), Ok((&[][..], -22 * 60 - 11)));
         assert_eq!(super::timezone(b"Z "), Ok((&b" "[..], 0)));
         assert_eq!(super::timezone(b"Z"), Ok((&[][..], 0)));
+        assert_eq!(
+            super::timezone("\u{2212}05:00".as_bytes()),
+            Ok((&[][..], -5 * 60))
+        );
+        assert_eq!(
+            super::timezone("\u{2212}0530".as_bytes()),
+            Ok((&[][..], -5 * 60 - 30))
+        );
     }
 
     #[test]
@@ -0,0 +1,233 @@
+//! nom parsers for embedding in other grammars.
+//!
+//! Every parser is generic over the nom error type,
+//! so it composes with grammars that use `nom::error::Error`,
+//! `VerboseError` or their own `ParseError`.
+//! Errors report the position and `ErrorKind` where parsing failed.
+//!
+//! The grammar itself is written against `nom::error::Error`
+//! and the result is converted with `ParseError::from_error_kind`.
+//! A `VerboseError` therefore holds that one entry,
+//! without the kinds or contexts of the parsers it failed inside.
+//! Add context around these parsers in the embedding grammar instead.
+//!
+//! The names and signatures here follow semver,
+//! except for those hidden from the documentation.
+//! nom is a public dependency, so moving to a new major version of nom
+//! is a breaking change of this crate.
+//!
+//! Like nom's streaming parsers, these may return `Incomplete`
+//! at the end of input. Wrap them in `nom::combinator::complete`
+//! for input that is known to be complete.
+//!
+//! ```
+//! use iso_8601::{parsers, Date, DateTime, GlobalTime};
+//! use nom::{bytes::complete::tag, error::ParseError, sequence::delimited, IResult};
+//!
+//! fn stamp<'a, E: ParseError<&'a [u8]>>(
+//!     i: &'a [u8],
+//! ) -> IResult<&'a [u8], DateTime<Date, GlobalTime>, E> {
+//!     delimited(tag("["), parsers::datetime_global_hms, tag("]"))(i)
+//! }
+//!
+//! let (rest, dt) = stamp::<nom::error::Error<_>>(b"[2018-08-02T13:42:02Z] started").unwrap();
+//! assert_eq!(rest, b" started");
+//! assert_eq!(dt.time.timezone, 0);
+//! assert!(stamp::<()>(b"[2018-08-02] started").is_err());
+//! ```
+
+use crate::{date::*, datetime::*, time::*, Formats, Sign};
+use nom::{error::ParseError, IResult};
+
+macro_rules! parsers {
+    ($($(#[$attr:meta])* $name:ident => $ty:ty;)*) => {
+        $(
+            $(#[$attr])*
+            pub fn $name<'a, E>(i: &'a [u8]) -> IResult<&'a [u8], $ty, E>
+            where
+                E: ParseError<&'a [u8]>,
+            {
+                crate::parse::$name(i).map_err(|err| err.map(|e| E::from_error_kind(e.input, e.code)))
+            }
+        )*
+    };
+}
+
+parsers! {
+    /// `+`, `-` or the Unicode minus sign or hyphen.
+    sign                               => Sign;
+
+    /// `2018-08-02`, `20180802`
+    date_ymd                           => YmdDate;
+    /// `2018-W31-4`, `2018W314`
+    date_wd                            => WdDate;
+    /// `2018-214`, `2018214`
+    date_o                             => ODate;
+    /// Any complete date.
+    date                               => Date;
+    /// `2018-W31`, `2018W31`
+    date_w                             => WDate;
+    /// `2018-08`
+    date_ym                            => YmDate;
+    /// `2018`
+    date_y                             => YDate;
+    /// `20`
+    date_c                             => CDate;
+    /// Any complete or reduced accuracy date.
+    date_approx                        => ApproxDate;
+
+    /// `13:42:02`, `134202`
+    time_hms                           => HmsTime;
+    /// `13:42`, `1342`
+    time_hm                            => HmTime;
+    /// `13`
+    time_h                             => HTime;
+    /// Any of hours, minutes and seconds.
+    time_naive_approx                  => ApproxNaiveTime;
+    /// `13:42:02.5`, `T134202.5`
+    time_local_hms                     => LocalTime<HmsTime>;
+    /// `13:42.5`, `T1342.5`
+    time_local_hm                      => LocalTime<HmTime>;
+    /// `13.5`, `T13.5`
+    time_local_h                       => LocalTime<HTime>;
+    /// Local time of any accuracy.
+    time_local_approx                  => ApproxLocalTime;
+    /// `13:42:02Z`, `134202+0200`
+    time_global_hms                    => GlobalTime<HmsTime>;
+    /// `13:42Z`, `1342+0200`
+    time_global_hm                     => GlobalTime<HmTime>;
+    /// `13Z`, `13+02`
+    time_global_h                      => GlobalTime<HTime>;
+    /// Time with a difference from UTC of any accuracy.
+    time_global_approx                 => ApproxGlobalTime;
+    /// Local or global `13:42:02`.
+    time_any_hms                       => AnyTime<HmsTime>;
+    /// Local or global `13:42`.
+    time_any_hm                        => AnyTime<HmTime>;
+    /// Local or global `13`.
+    time_any_h                         => AnyTime<HTime>;
+    /// Local or global time of any accuracy.
+    time_any_approx                    => ApproxAnyTime;
+
+    /// `2018-08-02T13:42:02Z`
+    datetime_global_hms                => DateTime<Date, GlobalTime<HmsTime>>;
+    /// `2018-08-02T13:42Z`
+    datetime_global_hm                 => DateTime<Date, GlobalTime<HmTime>>;
+    /// `2018-08-02T13Z`
+    datetime_global_h                  => DateTime<Date, GlobalTime<HTime>>;
+    /// `2018-08-02T13:42:02`
+    datetime_local_hms                 => DateTime<Date, LocalTime<HmsTime>>;
+    /// `2018-08-02T13:42`
+    datetime_local_hm                  => DateTime<Date, LocalTime<HmTime>>;
+    /// `2018-08-02T13`
+    datetime_local_h                   => DateTime<Date, LocalTime<HTime>>;
+    /// Local or global `2018-08-02T13:42:02`.
+    datetime_any_hms                   => DateTime<Date, AnyTime<HmsTime>>;
+    /// Local or global `2018-08-02T13:42`.
+    datetime_any_hm                    => DateTime<Date, AnyTime<HmTime>>;
+    /// Local or global `2018-08-02T13`.
+    datetime_any_h                     => DateTime<Date, AnyTime<HTime>>;
+    /// `datetime_global_hms` without the fast path for RFC 3339,
+    /// trying each shape in turn. Only for the benchmarks.
+    #[doc(hidden)]
+    datetime_global_hms_general        => DateTime<Date, GlobalTime<HmsTime>>;
+    /// `datetime_any_hms` without the fast path for RFC 3339.
+    /// Only for the benchmarks.
+    #[doc(hidden)]
+    datetime_any_hms_general           => DateTime<Date, AnyTime<HmsTime>>;
+    /// A complete date with a global time of any accuracy.
+    datetime_global_approx             => DateTime<Date, ApproxGlobalTime>;
+    /// A complete date with a local time of any accuracy.
+    datetime_local_approx              => DateTime<Date, ApproxLocalTime>;
+    /// A complete date with a local or global time of any accuracy.
+    datetime_any_approx                => DateTime<Date, ApproxAnyTime>;
+    /// `2018-W31T13:42:02Z`
+    datetime_approx_global_hms         => DateTime<ApproxDate, GlobalTime<HmsTime>>;
+    /// `2018-W31T13:42Z`
+    datetime_approx_global_hm          => DateTime<ApproxDate, GlobalTime<HmTime>>;
+    /// `2018-W31T13Z`
+    datetime_approx_global_h           => DateTime<ApproxDate, GlobalTime<HTime>>;
+    /// `2018-W31T13:42:02`
+    datetime_approx_local_hms          => DateTime<ApproxDate, LocalTime<HmsTime>>;
+    /// `2018-W31T13:42`
+    datetime_approx_local_hm           => DateTime<ApproxDate, LocalTime<HmTime>>;
+    /// `2018-W31T13`
+    datetime_approx_local_h            => DateTime<ApproxDate, LocalTime<HTime>>;
+    /// Local or global `2018-W31T13:42:02`.
+    datetime_approx_any_hms            => DateTime<ApproxDate, AnyTime<HmsTime>>;
+    /// Local or global `2018-W31T13:42`.
+    datetime_approx_any_hm             => DateTime<ApproxDate, AnyTime<HmTime>>;
+    /// Local or global `2018-W31T13`.
+    datetime_approx_any_h              => DateTime<ApproxDate, AnyTime<HTime>>;
+    /// A date and a global time, both of any accuracy.
+    datetime_approx_global_approx      => DateTime<ApproxDate, ApproxGlobalTime>;
+    /// A date and a local time, both of any accuracy.
+    datetime_approx_local_approx       => DateTime<ApproxDate, ApproxLocalTime>;
+    /// Any date and time of any accuracy, like `2018-W31T13+02`.
+    datetime_approx_any_approx         => DateTime<ApproxDate, ApproxAnyTime>;
+
+    /// A date, a time or both, decided by the input.
+    partial_datetime_approx_any_approx => PartialDateTime<ApproxDate, ApproxAnyTime>;
+    /// Like `partial_datetime_approx_any_approx`,
+    /// with the format each part was written in.
+    partial_datetime_formats           => (PartialDateTime<ApproxDate, ApproxAnyTime>, Formats);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{
+        bytes::complete::tag,
+        combinator::complete,
+        error::{Error, ErrorKind},
+        sequence::{preceded, terminated},
+        Err,
+    };
+
+    #[test]
+    fn error_position() {
+        assert_eq!(
+            time_global_hms::<Error<_>>(b"13:42:02 "),
+            Err(Err::Error(Error::new(&b" "[..], ErrorKind::Char)))
+        );
+        assert_eq!(
+            date_ymd::<Error<_>>(b"2018-08-0x"),
+            Err(Err::Error(Error::new(
+                &b"-08-0x"[..],
+                ErrorKind::TakeWhileMN
+            )))
+        );
+        assert_eq!(complete(time_hms::<()>)(b"13:42"), Err(Err::Error(())));
+    }
+
+    #[test]
+    fn compose() {
+        let mut line = preceded(
+            tag::<_, _, ()>("at "),
+            terminated(datetime_global_hms, tag(";")),
+        );
+        let (rest, dt) = line(b"at 20180802T134202Z; ok").unwrap();
+        assert_eq!(rest, b" ok");
+        assert_eq!(
+            dt.date,
+            Date::YMD(YmdDate {
+                year: 2018,
+                month: 8,
+                day: 2,
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn verbose_error() {
+        use nom::error::VerboseError;
+
+        let err = match time_global_hms::<VerboseError<_>>(b"13:42:02 ") {
+            Err(Err::Error(err)) => err,
+            result => panic!("{:?}", result),
+        };
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(err.errors[0].0, &b" "[..]);
+    }
+}
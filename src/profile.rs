@@ -0,0 +1,442 @@
+//! Parsers that accept only the expressions of a profile of ISO 8601,
+//! for interfaces that must not accept everything the standard allows.
+//!
+//! Unlike the general parsers, these consume the whole input.
+//! Those for other standards also reject dates and times that do not exist.
+
+use crate::{date::*, datetime::*, parse, time::*, EndOfDay, Format, Formats, Valid};
+use core::fmt;
+
+/// Why input does not match a profile.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Error {
+    /// The input is not written as the profile requires.
+    Syntax,
+    /// The date or time does not exist, like `2018-02-30` or `24:00`.
+    Invalid,
+    /// Some parts are in basic and others in extended format.
+    Mixed,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::Syntax => "not an expression of the profile",
+            Error::Invalid => "nonexistent date or time",
+            Error::Mixed => "mixed basic and extended format",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Whether the input is made of digits where the pattern has `D`
+/// and is the same as the pattern elsewhere.
+fn shape(i: &[u8], pattern: &[u8]) -> bool {
+    i.len() == pattern.len()
+        && i.iter().zip(pattern).all(|(&b, &p)| match p {
+            b'D' => b.is_ascii_digit(),
+            p => b == p,
+        })
+}
+
+/// Checks the ranges of a time zone designator
+/// that the parser already read as `Z` or `±hh:mm`.
+fn offset(zone: &[u8]) -> Result<(), Error> {
+    match zone {
+        [b'+', offset @ ..] | [b'-', offset @ ..]
+            if offset[..2] > b"23"[..] || offset[3..] > b"59"[..] =>
+        {
+            Err(Error::Invalid)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Gives a fraction of nines that `f32` rounded up to one
+/// the largest value below one, since the digits were a valid fraction.
+fn below_one(fraction: &mut f32) {
+    *fraction = fraction.min(1. - f32::EPSILON / 2.);
+}
+
+/// Whether the date and time exist, checking a second of `60`
+/// against [`LeapSeconds::current`](crate::LeapSeconds::current).
+#[cfg(feature = "leap-seconds")]
+fn exists(dt: &DateTime<Date, GlobalTime>) -> bool {
+    dt.is_valid_strict()
+}
+
+/// Whether the date and time exist, allowing a second of `60` on any day.
+#[cfg(not(feature = "leap-seconds"))]
+fn exists(dt: &DateTime<Date, GlobalTime>) -> bool {
+    dt.is_valid()
+}
+
+/// Internet date and time (RFC 3339 5.6): `YYYY-MM-DDThh:mm:ss[.f](Z|±hh:mm)`.
+///
+/// Also accepts `t` or a space for `T` and `z` for `Z`.
+/// A second of `60` is only accepted for a known leap second,
+/// or on any day without the `leap-seconds` feature.
+///
+/// ```
+/// use iso_8601::profile::{self, Error};
+///
+/// assert!(profile::rfc3339("2018-08-02T13:42:02.5+02:00").is_ok());
+/// assert!(profile::rfc3339("2018-08-02 13:42:02z").is_ok());
+/// assert_eq!(profile::rfc3339("2018-08-02T13:42+02:00"), Err(Error::Syntax));
+/// assert_eq!(profile::rfc3339("20180802T134202Z"), Err(Error::Syntax));
+/// assert_eq!(profile::rfc3339("2018-02-30T13:42:02Z"), Err(Error::Invalid));
+/// ```
+pub fn rfc3339(i: &str) -> Result<DateTime<Date, GlobalTime>, Error> {
+    let i = i.as_bytes();
+    let mut dt = match parse::rfc3339(i, true) {
+        Some(([], dt)) => dt,
+        _ => return Err(Error::Syntax),
+    };
+    offset(&i[i.len() - 6..])?;
+    below_one(&mut dt.time.local.fraction);
+    if !exists(&dt) || dt.time.is_end_of_day() {
+        return Err(Error::Invalid);
+    }
+    Ok(dt)
+}
+
+/// W3C Date and Time Formats (W3C-DTF) in one of its six granularities:
+/// `YYYY`, `YYYY-MM`, `YYYY-MM-DD`, and that followed by
+/// `Thh:mm`, `Thh:mm:ss` or `Thh:mm:ss.s` and `Z` or `±hh:mm`.
+///
+/// Leap seconds are checked like in [`rfc3339`].
+///
+/// ```
+/// use iso_8601::{profile, ApproxDate, PartialDateTime, YDate};
+///
+/// assert_eq!(
+///     profile::w3c_dtf("2018"),
+///     Ok(PartialDateTime::Date(ApproxDate::Y(YDate { year: 2018 })))
+/// );
+/// assert!(profile::w3c_dtf("2018-08-02T13:42Z").is_ok());
+/// assert!(profile::w3c_dtf("2018-08-02T13Z").is_err());
+/// assert!(profile::w3c_dtf("2018-W31").is_err());
+/// ```
+pub fn w3c_dtf(i: &str) -> Result<PartialDateTime<ApproxDate, ApproxGlobalTime>, Error> {
+    let i = i.as_bytes();
+    if i.len() <= 10 {
+        if ![4, 7, 10].contains(&i.len()) || !shape(i, &b"DDDD-DD-DD"[..i.len()]) {
+            return Err(Error::Syntax);
+        }
+        let date = match parse::date_approx(i) {
+            Ok(([], date)) => date,
+            _ => return Err(Error::Syntax),
+        };
+        return if date.is_valid() {
+            Ok(PartialDateTime::Date(date))
+        } else {
+            Err(Error::Invalid)
+        };
+    }
+
+    if !shape(&i[..11], b"DDDD-DD-DDT") {
+        return Err(Error::Syntax);
+    }
+    let zone = i
+        .iter()
+        .rposition(|&b| matches!(b, b'Z' | b'+' | b'-'))
+        .filter(|&zone| zone > 10)
+        .ok_or(Error::Syntax)?;
+    let time = &i[11..zone];
+    let time_ok = match time.len() {
+        5 => shape(time, b"DD:DD"),
+        8 => shape(time, b"DD:DD:DD"),
+        len => {
+            len > 9 && shape(&time[..9], b"DD:DD:DD.") && time[9..].iter().all(u8::is_ascii_digit)
+        }
+    };
+    if !time_ok || !(i[zone..] == *b"Z" || shape(&i[zone + 1..], b"DD:DD")) {
+        return Err(Error::Syntax);
+    }
+    offset(&i[zone..])?;
+
+    let mut dt = match parse::datetime_approx_global_approx(i) {
+        Ok(([], dt)) => dt,
+        _ => return Err(Error::Syntax),
+    };
+    let exists = match (&dt.date, &mut dt.time) {
+        (ApproxDate::YMD(date), ApproxGlobalTime::HMS(time)) => {
+            below_one(&mut time.local.fraction);
+            exists(&DateTime {
+                date: Date::YMD(date.clone()),
+                time: time.clone(),
+            })
+        }
+        _ => dt.is_valid(),
+    };
+    if !exists || dt.time.is_end_of_day() {
+        return Err(Error::Invalid);
+    }
+    Ok(PartialDateTime::DateTime(dt))
+}
+
+fn formats(i: &str) -> Result<(PartialDateTime, Formats), Error> {
+    match parse::partial_datetime_formats(i.as_bytes()) {
+        Ok(([], parsed)) => Ok(parsed),
+        _ => Err(Error::Syntax),
+    }
+}
+
+fn only(i: &str, format: Format) -> Result<PartialDateTime, Error> {
+    match parse::partial_datetime_format(i.as_bytes(), format) {
+        Ok(([], value)) => Ok(value),
+        _ => match formats(i) {
+            Ok((_, formats)) if formats.is_mixed() => Err(Error::Mixed),
+            _ => Err(Error::Syntax),
+        },
+    }
+}
+
+/// Any date, time or datetime written in basic format only.
+///
+/// Parts that read the same in both formats, like `2018`, `2018-08` or `13`,
+/// are accepted. Values are not validated.
+///
+/// ```
+/// use iso_8601::profile::{self, Error};
+///
+/// assert!(profile::basic("20180802T134202+0200").is_ok());
+/// assert!(profile::basic("2018-08").is_ok());
+/// assert_eq!(profile::basic("2018-08-02"), Err(Error::Syntax));
+/// assert_eq!(profile::basic("20180802T13:42:02"), Err(Error::Mixed));
+/// ```
+pub fn basic(i: &str) -> Result<PartialDateTime, Error> {
+    only(i, Format::Basic)
+}
+
+/// Any date, time or datetime written in extended format only.
+///
+/// Parts that read the same in both formats, like `2018`, `2018-08` or `13`,
+/// are accepted. Values are not validated.
+///
+/// ```
+/// use iso_8601::profile::{self, Error};
+///
+/// assert!(profile::extended("2018-08-02T13:42:02+02:00").is_ok());
+/// assert!(profile::extended("2018").is_ok());
+/// assert_eq!(profile::extended("20180802"), Err(Error::Syntax));
+/// assert_eq!(profile::extended("2018-08-02T134202"), Err(Error::Mixed));
+/// ```
+pub fn extended(i: &str) -> Result<PartialDateTime, Error> {
+    only(i, Format::Extended)
+}
+
+/// Any date, time or datetime in either format,
+/// as long as all its parts are in the same one,
+/// with the format of each part.
+///
+/// Values are not validated.
+///
+/// ```
+/// use iso_8601::{profile::{self, Error}, Format};
+///
+/// let (_, formats) = profile::unmixed("2018-08-02T13:42:00").unwrap();
+/// assert_eq!(formats.format(), Some(Format::Extended));
+/// assert_eq!(profile::unmixed("20180802T13:42:00"), Err(Error::Mixed));
+/// ```
+pub fn unmixed(i: &str) -> Result<(PartialDateTime, Formats), Error> {
+    match formats(i)? {
+        (_, formats) if formats.is_mixed() => Err(Error::Mixed),
+        parsed => Ok(parsed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc3339() {
+        let expected = DateTime {
+            date: Date::YMD(YmdDate {
+                year: 2018,
+                month: 8,
+                day: 2,
+            }),
+            time: GlobalTime {
+                local: LocalTime {
+                    naive: HmsTime {
+                        hour: 13,
+                        minute: 42,
+                        second: 2,
+                    },
+                    fraction: 0.5,
+                },
+                timezone: -(5 * 60 + 30),
+            },
+        };
+        for input in &[
+            "2018-08-02T13:42:02.5-05:30",
+            "2018-08-02t13:42:02.5-05:30",
+            "2018-08-02 13:42:02.5-05:30",
+        ] {
+            assert_eq!(super::rfc3339(input), Ok(expected.clone()));
+        }
+        assert!(super::rfc3339("2018-08-02T13:42:02z").is_ok());
+        assert!(super::rfc3339("2016-12-31T23:59:60Z").is_ok());
+        assert!(super::rfc3339("2016-12-31T18:59:60-05:00").is_ok());
+        assert_eq!(
+            super::rfc3339("2018-08-02T13:42:60Z").is_ok(),
+            cfg!(not(feature = "leap-seconds"))
+        );
+        let nines = super::rfc3339("2018-08-02T13:42:02.99999999999Z").unwrap();
+        assert!(nines.time.local.fraction < 1.);
+        assert_eq!(nines.time.local.naive.second, 2);
+
+        for input in &[
+            "2018-08-02T13:42:02",
+            "2018-08-02T13:42:02Z ",
+            "2018-08-02T13:42:02.Z",
+            "2018-08-02T13:42:02,5Z",
+            "2018-08-02T13:42:02+0200",
+            "2018-08-02T13:42:02+02",
+            "2018-08-02T13:42:02\u{2212}02:00",
+            "2018-08-02\t13:42:02Z",
+            "2018-214T13:42:02Z",
+            "+2018-08-02T13:42:02Z",
+        ] {
+            assert_eq!(super::rfc3339(input), Err(Error::Syntax), "{}", input);
+        }
+        for input in &[
+            "2018-13-02T13:42:02Z",
+            "2018-08-02T24:00:00Z",
+            "2018-08-02T13:60:02Z",
+            "2018-08-02T13:42:02+24:00",
+            "2018-08-02T13:42:02+01:60",
+        ] {
+            assert_eq!(super::rfc3339(input), Err(Error::Invalid), "{}", input);
+        }
+    }
+
+    #[test]
+    fn w3c_dtf() {
+        for input in &[
+            "2018",
+            "2018-08",
+            "2018-08-02",
+            "2018-08-02T13:42Z",
+            "2018-08-02T13:42:02+02:00",
+            "2018-08-02T13:42:02.45-05:30",
+            "2018-08-02T13:42:02.99999999999Z",
+            "2016-12-31T23:59:60Z",
+        ] {
+            assert!(super::w3c_dtf(input).is_ok(), "{}", input);
+        }
+        assert_eq!(
+            super::w3c_dtf("2018-08-02T13:42:60Z").is_ok(),
+            cfg!(not(feature = "leap-seconds"))
+        );
+        assert_eq!(
+            super::w3c_dtf("2018-08"),
+            Ok(PartialDateTime::Date(ApproxDate::YM(YmDate {
+                year: 2018,
+                month: 8,
+            })))
+        );
+
+        for input in &[
+            "",
+            "20",
+            "201808",
+            "20180802",
+            "2018-214",
+            "2018-W31-4",
+            "2018-08-02T",
+            "2018-08-02T13:42",
+            "2018-08-02T13Z",
+            "2018-08-02T1342Z",
+            "2018-08-02T13:42:02.Z",
+            "2018-08-02T13:42:02+02",
+            "2018-08-02T13:42:02+0200",
+            "2018-08-02t13:42Z",
+            "-2018-08-02",
+        ] {
+            assert_eq!(super::w3c_dtf(input), Err(Error::Syntax), "{}", input);
+        }
+        for input in &[
+            "2018-13",
+            "2018-02-29",
+            "2018-08-02T24:00Z",
+            "2018-08-02T13:42-01:99",
+        ] {
+            assert_eq!(super::w3c_dtf(input), Err(Error::Invalid), "{}", input);
+        }
+    }
+
+    #[test]
+    fn basic() {
+        for input in &[
+            "20180802",
+            "2018W314",
+            "20180802T134202.5-0530",
+            "T1342",
+            "2018",
+            "2018-08",
+        ] {
+            assert!(super::basic(input).is_ok(), "{}", input);
+        }
+        for input in &["2018-08-02", "13:42", "2018-08-02 "] {
+            assert_eq!(super::basic(input), Err(Error::Syntax), "{}", input);
+        }
+        for input in &["20180802T134202+02:00", "20180802T13:42"] {
+            assert_eq!(super::basic(input), Err(Error::Mixed), "{}", input);
+        }
+    }
+
+    #[test]
+    fn extended() {
+        for input in &[
+            "2018-08-02",
+            "2018-W31-4",
+            "2018-08-02T13:42:02.5-05:30",
+            "13:42",
+            "2018",
+            "T13Z",
+        ] {
+            assert!(super::extended(input).is_ok(), "{}", input);
+        }
+        for input in &["20180802", "T1342"] {
+            assert_eq!(super::extended(input), Err(Error::Syntax), "{}", input);
+        }
+        for input in &["2018-08-02T1342", "2018-08-02T13:42+0200"] {
+            assert_eq!(super::extended(input), Err(Error::Mixed), "{}", input);
+        }
+    }
+
+    #[test]
+    fn unmixed() {
+        use Format::*;
+
+        assert_eq!(
+            super::unmixed("20180802T134200Z").map(|(_, formats)| formats),
+            Ok(Formats {
+                date: Some(Basic),
+                time: Some(Basic),
+                timezone: None,
+            })
+        );
+        assert_eq!(
+            super::unmixed("2018-08T13:42+02:00").map(|(_, formats)| formats),
+            Ok(Formats {
+                date: None,
+                time: Some(Extended),
+                timezone: Some(Extended),
+            })
+        );
+        for input in &[
+            "20180802T13:42:00",
+            "2018-08-02T1342",
+            "2018-08-02T13:42+0200",
+        ] {
+            assert_eq!(super::unmixed(input), Err(Error::Mixed), "{}", input);
+        }
+        assert_eq!(super::unmixed("2018-08-02 13:42"), Err(Error::Syntax));
+    }
+}
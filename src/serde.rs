@@ -0,0 +1,589 @@
+#![cfg(feature = "serde")]
+//! All types serialize as ISO 8601 strings in extended format,
+//! borrowing the input where the format allows.
+//! The modules here select another representation per field
+//! with `#[serde(with = "...")]`.
+//!
+//! Formats that are not human-readable, like bincode,
+//! get a compact encoding of complete dates and times instead:
+//! - dates as a tuple of year and day of the year,
+//!   with a leading tag of the representation for [`Date`],
+//! - [`LocalTime`] as a tuple of hour, minute, second and nanosecond,
+//! - [`GlobalTime`] as that plus the difference from UTC in minutes,
+//! - [`DateTime`] as a tuple of its date and time.
+//!
+//! All other types remain strings.
+//! Values that are not valid fail to serialize in the compact encoding.
+
+extern crate serde;
+
+use self::serde::{
+    de::{self, Unexpected, Visitor},
+    ser, Deserialize, Deserializer, Serialize, Serializer,
+};
+use crate::{
+    AnyTime, ApproxAnyTime, ApproxDate, ApproxGlobalTime, ApproxLocalTime, ApproxNaiveTime, CDate,
+    Date, DateTime, Datelike, GlobalTime, HTime, HmTime, HmsTime, LocalTime, NaiveTime, ODate,
+    PartialDateTime, Timelike, Valid, WDate, WdDate, YDate, Year, YmDate, YmdDate,
+};
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+struct FromStrVisitor<T>(PhantomData<T>);
+
+impl<'de, T: FromStr> Visitor<'de> for FromStrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an ISO 8601 string")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        s.parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        visit_utf8(self, v)
+    }
+}
+
+/// Visits bytes as the string they encode, if any.
+fn visit_utf8<'de, V, E>(visitor: V, v: &[u8]) -> Result<V::Value, E>
+where
+    V: Visitor<'de>,
+    E: de::Error,
+{
+    match std::str::from_utf8(v) {
+        Ok(s) => visitor.visit_str(s),
+        Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &visitor)),
+    }
+}
+
+/// Generates `option` and `vec` modules
+/// from the `serialize` and `deserialize` functions of the enclosing module.
+macro_rules! impl_with_collections {
+    ($($bound:tt)*) => {
+        struct Ser<'a, T>(&'a T);
+
+        impl<T: fmt::Display> Serialize for Ser<'_, T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize(self.0, serializer)
+            }
+        }
+
+        struct De<T>(T);
+
+        impl<'de, T: $($bound)*> Deserialize<'de> for De<T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize(deserializer).map(De)
+            }
+        }
+
+        /// For `Option` fields, with `None` as null.
+        pub mod option {
+            use super::*;
+
+            pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: fmt::Display,
+                S: Serializer,
+            {
+                value.as_ref().map(Ser).serialize(serializer)
+            }
+
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+            where
+                T: $($bound)*,
+                D: Deserializer<'de>,
+            {
+                Option::<De<T>>::deserialize(deserializer).map(|value| value.map(|De(value)| value))
+            }
+        }
+
+        /// For `Vec` fields, as a sequence.
+        pub mod vec {
+            use super::*;
+
+            pub fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: fmt::Display,
+                S: Serializer,
+            {
+                serializer.collect_seq(values.iter().map(Ser))
+            }
+
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+            where
+                T: $($bound)*,
+                D: Deserializer<'de>,
+            {
+                Vec::<De<T>>::deserialize(deserializer)
+                    .map(|values| values.into_iter().map(|De(value)| value).collect())
+            }
+        }
+    };
+}
+
+/// Extended format, as used by the `Serialize` implementations,
+/// for example `2018-08-02T13:42:00Z`.
+/// Deserializing accepts both formats.
+pub mod extended {
+    use super::*;
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(FromStrVisitor(PhantomData))
+    }
+
+    impl_with_collections!(FromStr);
+}
+
+/// Basic format, for example `20180802T1342Z`.
+/// Deserializing accepts both formats.
+pub mod basic {
+    use super::*;
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(&format_args!("{:#}", value))
+    }
+
+    pub use super::extended::deserialize;
+
+    impl_with_collections!(FromStr);
+}
+
+/// Extended format, rejecting dates and times
+/// that do not exist on deserialization, like `2018-02-30`.
+/// See [`Valid`].
+pub mod strict {
+    use super::*;
+
+    struct ValidVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: FromStr + Valid> Visitor<'de> for ValidVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a valid ISO 8601 string")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+            s.parse()
+                .ok()
+                .filter(Valid::is_valid)
+                .ok_or_else(|| E::invalid_value(Unexpected::Str(s), &self))
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+            visit_utf8(self, v)
+        }
+    }
+
+    pub use super::extended::serialize;
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr + Valid,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ValidVisitor(PhantomData))
+    }
+
+    impl_with_collections!(FromStr + Valid);
+}
+
+/// Serializes as an ISO 8601 string in extended format
+/// and deserializes with the parser for the type.
+macro_rules! impl_serde {
+    ($(impl<$($param:ident: $bound:path),*> $ty:ty;)*) => {
+        $(
+            impl<$($param: $bound),*> Serialize for $ty
+            where
+                Self: fmt::Display,
+            {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de, $($param: $bound),*> Deserialize<'de> for $ty
+            where
+                Self: FromStr,
+            {
+                fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+                    deserializer.deserialize_str(FromStrVisitor(PhantomData))
+                }
+            }
+        )*
+    };
+}
+
+impl_serde! {
+    impl<Y: Year> ApproxDate<Y>;
+    impl<Y: Year> YmDate<Y>;
+    impl<Y: Year> YDate<Y>;
+    impl<> CDate;
+    impl<Y: Year> WDate<Y>;
+    impl<> HmsTime;
+    impl<> HmTime;
+    impl<> HTime;
+    impl<> LocalTime<HmTime>;
+    impl<> LocalTime<HTime>;
+    impl<> GlobalTime<HmTime>;
+    impl<> GlobalTime<HTime>;
+    impl<N: NaiveTime> AnyTime<N>;
+    impl<> ApproxNaiveTime;
+    impl<> ApproxLocalTime;
+    impl<> ApproxGlobalTime;
+    impl<> ApproxAnyTime;
+    impl<D: Datelike, T: Timelike> PartialDateTime<D, T>;
+}
+
+/// Encoding for formats that are not human-readable.
+trait Compact: Sized {
+    type Repr: Serialize + for<'de> Deserialize<'de>;
+
+    /// `None` if the value is invalid.
+    fn to_compact(&self) -> Option<Self::Repr>;
+
+    /// `None` if the encoded value is invalid.
+    fn from_compact(repr: Self::Repr) -> Option<Self>;
+}
+
+impl Compact for ODate {
+    type Repr = (i16, u16);
+
+    fn to_compact(&self) -> Option<Self::Repr> {
+        Some((self.year, self.day)).filter(|_| self.is_valid())
+    }
+
+    fn from_compact((year, day): Self::Repr) -> Option<Self> {
+        Some(ODate { year, day }).filter(Valid::is_valid)
+    }
+}
+
+impl Compact for YmdDate {
+    type Repr = (i16, u16);
+
+    fn to_compact(&self) -> Option<Self::Repr> {
+        if !self.is_valid() {
+            return None;
+        }
+        ODate::from(self.clone()).to_compact()
+    }
+
+    fn from_compact(repr: Self::Repr) -> Option<Self> {
+        ODate::from_compact(repr).map(Into::into)
+    }
+}
+
+impl Compact for WdDate {
+    type Repr = (i16, u16);
+
+    fn to_compact(&self) -> Option<Self::Repr> {
+        if !self.is_valid() {
+            return None;
+        }
+        ODate::from(self.clone()).to_compact()
+    }
+
+    fn from_compact(repr: Self::Repr) -> Option<Self> {
+        ODate::from_compact(repr).map(Into::into)
+    }
+}
+
+/// Tags the representation, so that a date decodes as it was written.
+impl Compact for Date {
+    type Repr = (u8, i16, u16);
+
+    fn to_compact(&self) -> Option<Self::Repr> {
+        let (tag, (year, day)) = match self {
+            Date::YMD(date) => (0, date.to_compact()?),
+            Date::WD(date) => (1, date.to_compact()?),
+            Date::O(date) => (2, date.to_compact()?),
+        };
+        Some((tag, year, day))
+    }
+
+    fn from_compact((tag, year, day): Self::Repr) -> Option<Self> {
+        match tag {
+            0 => YmdDate::from_compact((year, day)).map(Date::YMD),
+            1 => WdDate::from_compact((year, day)).map(Date::WD),
+            2 => ODate::from_compact((year, day)).map(Date::O),
+            _ => None,
+        }
+    }
+}
+
+impl Compact for LocalTime {
+    type Repr = (u8, u8, u8, u32);
+
+    fn to_compact(&self) -> Option<Self::Repr> {
+        Some((
+            self.naive.hour,
+            self.naive.minute,
+            self.naive.second,
+            self.nanosecond().min(999_999_999),
+        ))
+        .filter(|_| self.is_valid())
+    }
+
+    fn from_compact((hour, minute, second, nanosecond): Self::Repr) -> Option<Self> {
+        Some(LocalTime {
+            naive: HmsTime {
+                hour,
+                minute,
+                second,
+            },
+            fraction: crate::datetime::fraction(nanosecond),
+        })
+        .filter(|_| nanosecond < 1_000_000_000)
+        .filter(Valid::is_valid)
+    }
+}
+
+impl Compact for GlobalTime {
+    type Repr = (u8, u8, u8, u32, i16);
+
+    fn to_compact(&self) -> Option<Self::Repr> {
+        let (hour, minute, second, nanosecond) = self.local.to_compact()?;
+        Some((hour, minute, second, nanosecond, self.timezone))
+    }
+
+    fn from_compact((hour, minute, second, nanosecond, timezone): Self::Repr) -> Option<Self> {
+        Some(GlobalTime {
+            local: LocalTime::from_compact((hour, minute, second, nanosecond))?,
+            timezone,
+        })
+    }
+}
+
+macro_rules! impl_serde_compact {
+    ($($ty:ty),*) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    if serializer.is_human_readable() {
+                        serializer.collect_str(self)
+                    } else {
+                        self.to_compact()
+                            .ok_or_else(|| ser::Error::custom("invalid date or time"))?
+                            .serialize(serializer)
+                    }
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    if deserializer.is_human_readable() {
+                        deserializer.deserialize_str(FromStrVisitor(PhantomData))
+                    } else {
+                        Self::from_compact(<Self as Compact>::Repr::deserialize(deserializer)?)
+                            .ok_or_else(|| de::Error::custom("invalid date or time"))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_compact!(Date, YmdDate, WdDate, ODate, LocalTime, GlobalTime);
+
+impl<D, T> Serialize for DateTime<D, T>
+where
+    D: Datelike + Serialize,
+    T: Timelike + Serialize,
+    Self: fmt::Display,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            (&self.date, &self.time).serialize(serializer)
+        }
+    }
+}
+
+impl<'de, D, T> Deserialize<'de> for DateTime<D, T>
+where
+    D: Datelike + Deserialize<'de>,
+    T: Timelike + Deserialize<'de>,
+    Self: FromStr,
+{
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(FromStrVisitor(PhantomData))
+        } else {
+            let (date, time) = Deserialize::deserialize(deserializer)?;
+            Ok(DateTime { date, time })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn round_trip<T>(s: &str)
+    where
+        T: Serialize + for<'de> Deserialize<'de> + fmt::Debug,
+    {
+        let json = format!("\"{}\"", s);
+        let value = serde_json::from_str::<T>(&json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap(), json, "{:?}", value);
+    }
+
+    #[test]
+    fn round_trips() {
+        round_trip::<Date>("2018-08-02");
+        round_trip::<ApproxDate>("2018-W31");
+        round_trip::<ODate>("2018-214");
+        round_trip::<HmsTime>("13:42:02");
+        round_trip::<LocalTime<HmTime>>("13:42.5");
+        round_trip::<GlobalTime>("13:42:02+02:00");
+        round_trip::<ApproxAnyTime>("13Z");
+        round_trip::<DateTime<Date, GlobalTime>>("2018-08-02T13:42:02.5Z");
+        round_trip::<DateTime<ApproxDate, ApproxLocalTime>>("2018-214T13:42");
+        round_trip::<PartialDateTime>("2018-08");
+    }
+
+    #[test]
+    fn invalid() {
+        let err = serde_json::from_str::<Date>("\"yesterday\"").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value: string \"yesterday\", expected an ISO 8601 string at line 1 column 11"
+        );
+        assert!(serde_json::from_str::<Date>("20180802").is_err());
+    }
+
+    #[test]
+    fn nested() {
+        let json = r#"[null,"17:00"]"#;
+        let times = serde_json::from_str::<Vec<Option<HmTime>>>(json).unwrap();
+        assert_eq!(
+            times,
+            vec![
+                None,
+                Some(HmTime {
+                    hour: 17,
+                    minute: 0
+                })
+            ]
+        );
+        assert_eq!(serde_json::to_string(&times).unwrap(), json);
+    }
+
+    #[test]
+    fn with() {
+        let mut json = Vec::new();
+        let dt = DateTime::<Date, GlobalTime<HmTime>>::from_str("2018-08-02T13:42Z").unwrap();
+        basic::serialize(&dt, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert_eq!(json, br#""20180802T1342Z""#);
+
+        let mut de = serde_json::Deserializer::from_str(r#""20180802T1342Z""#);
+        assert_eq!(extended::deserialize(&mut de).ok(), Some(dt));
+
+        let mut de = serde_json::Deserializer::from_str(r#""2018-02-30""#);
+        assert!(strict::deserialize::<Date, _>(&mut de).is_err());
+        let mut de = serde_json::Deserializer::from_str(r#""2018-02-28""#);
+        assert!(strict::deserialize::<Date, _>(&mut de).is_ok());
+    }
+
+    #[test]
+    fn with_collections() {
+        let dates = vec![Date::from_str("2018-08-02").unwrap()];
+        let mut json = Vec::new();
+        basic::vec::serialize(&dates, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert_eq!(json, br#"["20180802"]"#);
+
+        let mut de = serde_json::Deserializer::from_str(r#"["2018-08-02"]"#);
+        assert_eq!(strict::vec::deserialize(&mut de).ok(), Some(dates));
+        let mut de = serde_json::Deserializer::from_str(r#"["2018-08-02","2018-02-30"]"#);
+        assert!(strict::vec::deserialize::<Date, _>(&mut de).is_err());
+
+        let mut json = Vec::new();
+        extended::option::serialize::<Date, _>(&None, &mut serde_json::Serializer::new(&mut json))
+            .unwrap();
+        assert_eq!(json, b"null");
+
+        let mut de = serde_json::Deserializer::from_str(r#""20180802""#);
+        assert_eq!(
+            basic::option::deserialize(&mut de).ok(),
+            Some(Some(Date::from_str("2018-08-02").unwrap()))
+        );
+    }
+
+    #[test]
+    fn borrowed() {
+        let de = de::value::BorrowedBytesDeserializer::<de::value::Error>::new(b"2018-08-02");
+        assert_eq!(
+            Date::deserialize(de),
+            Ok(Date::from_str("2018-08-02").unwrap())
+        );
+
+        let de = de::value::BorrowedBytesDeserializer::<de::value::Error>::new(b"\xff");
+        assert!(Date::deserialize(de).is_err());
+    }
+
+    #[test]
+    fn compact() {
+        let dt = DateTime::<Date, GlobalTime>::from_str("2018-W31-4T13:42:02.5+02:00").unwrap();
+        let bytes = bincode::serialize(&dt).unwrap();
+        assert_eq!(bytes.len(), 1 + 2 + 2 + 3 + 4 + 2);
+        assert_eq!(
+            bincode::deserialize::<DateTime<Date, GlobalTime>>(&bytes).unwrap(),
+            dt
+        );
+
+        for date in &["2018-08-02", "2018-W31-4", "2018-214"] {
+            let date = Date::from_str(date).unwrap();
+            let bytes = bincode::serialize(&date).unwrap();
+            assert_eq!(bincode::deserialize::<Date>(&bytes).unwrap(), date);
+        }
+        let bytes = bincode::serialize(&(3u8, 2018i16, 214u16)).unwrap();
+        assert!(bincode::deserialize::<Date>(&bytes).is_err());
+
+        let invalid = Date::from_str("2018-13-01").unwrap();
+        assert!(bincode::serialize(&invalid).is_err());
+        assert!(bincode::serialize(&WdDate::from_str("2018-W54-1").unwrap()).is_err());
+        assert!(bincode::serialize(&LocalTime::<HmsTime>::from_str("25:00:00").unwrap()).is_err());
+
+        let time = LocalTime::from_str("23:59:60.25").unwrap();
+        let bytes = bincode::serialize(&time).unwrap();
+        assert_eq!(bincode::deserialize::<LocalTime>(&bytes).unwrap(), time);
+
+        let bytes = bincode::serialize(&(13u8, 42u8, 2u8, 999_999_999u32)).unwrap();
+        let time = bincode::deserialize::<LocalTime>(&bytes).unwrap();
+        assert!(time.is_valid());
+        let bytes = bincode::serialize(&time).unwrap();
+        assert_eq!(bincode::deserialize::<LocalTime>(&bytes).unwrap(), time);
+
+        let date = WdDate::from_str("2020-W53-7").unwrap();
+        let bytes = bincode::serialize(&date).unwrap();
+        assert_eq!(bincode::deserialize::<WdDate>(&bytes).unwrap(), date);
+
+        let bytes = bincode::serialize(&(2018i16, 366u16)).unwrap();
+        assert!(bincode::deserialize::<ODate>(&bytes).is_err());
+
+        let approx = ApproxDate::from_str("2018-08").unwrap();
+        let bytes = bincode::serialize(&approx).unwrap();
+        assert_eq!(bincode::deserialize::<ApproxDate>(&bytes).unwrap(), approx);
+    }
+}
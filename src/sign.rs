@@ -0,0 +1,103 @@
+use core::fmt;
+
+/// The sign of a year, century or difference from UTC
+/// as it was written (3.4.1).
+///
+/// The standard writes negative values with the minus sign (U+2212)
+/// and allows the hyphen-minus where that is not available.
+///
+/// Parsed values keep only the number, so the sign they were written with
+/// is kept by [`layout::Parsed`](crate::layout::Parsed):
+///
+/// ```
+/// use iso_8601::{layout::{Parsed, Timezone}, DateTime, Date, GlobalTime, Sign};
+///
+/// let parsed: Parsed<DateTime<Date, GlobalTime>> =
+///     "\u{2212}0044-03-15T12:00:00\u{2010}05:00".parse().unwrap();
+/// assert_eq!(parsed.layout.year_sign, Some(Sign::Minus));
+/// assert_eq!(parsed.layout.timezone, Some(Timezone::HM(Sign::Hyphen)));
+/// assert_eq!(parsed.value.time.timezone, -5 * 60);
+/// ```
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
+pub enum Sign {
+    /// `+`
+    Plus,
+    /// `-` (U+002D)
+    HyphenMinus,
+    /// `−` (U+2212)
+    Minus,
+    /// `‐` (U+2010)
+    Hyphen,
+}
+
+impl Sign {
+    /// `1` or `-1`.
+    pub fn signum(self) -> i8 {
+        match self {
+            Sign::Plus => 1,
+            Sign::HyphenMinus | Sign::Minus | Sign::Hyphen => -1,
+        }
+    }
+
+    /// Whether the sign is any of the minus signs.
+    pub fn is_negative(self) -> bool {
+        self.signum() < 0
+    }
+
+    /// Whether the sign is written in ASCII.
+    pub fn is_ascii(self) -> bool {
+        matches!(self, Sign::Plus | Sign::HyphenMinus)
+    }
+
+    /// The character the sign is written as.
+    pub fn as_char(self) -> char {
+        match self {
+            Sign::Plus => '+',
+            Sign::HyphenMinus => '-',
+            Sign::Minus => '\u{2212}',
+            Sign::Hyphen => '\u{2010}',
+        }
+    }
+}
+
+impl_fromstr_parse!(Sign, sign);
+
+impl fmt::Display for Sign {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Write::write_char(f, self.as_char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Sign::from_str("+"), Ok(Sign::Plus));
+        assert_eq!(Sign::from_str("-"), Ok(Sign::HyphenMinus));
+        assert_eq!(Sign::from_str("\u{2212}"), Ok(Sign::Minus));
+        assert_eq!(Sign::from_str("\u{2010}"), Ok(Sign::Hyphen));
+        assert_eq!(Sign::from_str("\u{2013}"), Err(()));
+    }
+
+    #[test]
+    fn signum() {
+        assert_eq!(Sign::Plus.signum(), 1);
+        assert!(!Sign::Plus.is_negative());
+        for sign in &[Sign::HyphenMinus, Sign::Minus, Sign::Hyphen] {
+            assert_eq!(sign.signum(), -1);
+            assert!(sign.is_negative());
+        }
+        assert!(Sign::HyphenMinus.is_ascii());
+        assert!(!Sign::Minus.is_ascii());
+    }
+
+    #[test]
+    fn display() {
+        for sign in &["+", "-", "\u{2212}", "\u{2010}"] {
+            assert_eq!(Sign::from_str(sign).unwrap().to_string(), *sign);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::Valid;
+use core::fmt;
 
 /// Local time (4.2.2.2)
 #[derive(Eq, PartialEq, Clone, Debug)]
@@ -57,6 +58,18 @@ impl NaiveTime for HmsTime {}
 impl NaiveTime for HmTime {}
 impl NaiveTime for HTime {}
 
+/// Times that can denote the end of a day as `24:00` (4.2.3).
+pub trait EndOfDay: Sized {
+    /// The beginning of the following day (`00:00`)
+    /// if this is the end of a day (`24:00`).
+    fn start_of_next_day(&self) -> Option<Self>;
+
+    /// Whether this is the end of a day (`24:00`).
+    fn is_end_of_day(&self) -> bool {
+        self.start_of_next_day().is_some()
+    }
+}
+
 impl LocalTime<HmsTime> {
     pub fn nanosecond(&self) -> u32 {
         (self.fraction * 1_000_000_000.) as u32
@@ -125,6 +138,10 @@ impl Timelike for ApproxLocalTime {}
 impl Timelike for ApproxGlobalTime {}
 impl Timelike for ApproxAnyTime {}
 
+impl_fromstr_parse!(HmsTime, time_hms);
+impl_fromstr_parse!(HmTime, time_hm);
+impl_fromstr_parse!(HTime, time_h);
+impl_fromstr_parse!(ApproxNaiveTime, time_naive_approx);
 impl_fromstr_parse!(GlobalTime<HmsTime>, time_global_hms);
 impl_fromstr_parse!(GlobalTime<HmTime>, time_global_hm);
 impl_fromstr_parse!(GlobalTime<HTime>, time_global_h);
@@ -138,17 +155,165 @@ impl_fromstr_parse!(ApproxGlobalTime, time_global_approx);
 impl_fromstr_parse!(ApproxLocalTime, time_local_approx);
 impl_fromstr_parse!(ApproxAnyTime, time_any_approx);
 
+/// The basic format, without separators (2.3.3).
+fn separator(f: &fmt::Formatter) -> &'static str {
+    if f.alternate() {
+        ""
+    } else {
+        ":"
+    }
+}
+
+/// Writes the extended format, or the basic format
+/// with the alternate flag (`{:#}`).
+impl fmt::Display for HmsTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sep = separator(f);
+        write!(
+            f,
+            "{:02}{}{:02}{}{:02}",
+            self.hour, sep, self.minute, sep, self.second
+        )
+    }
+}
+
+impl fmt::Display for HmTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}{}{:02}", self.hour, separator(f), self.minute)
+    }
+}
+
+impl fmt::Display for HTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}", self.hour)
+    }
+}
+
+/// Writes a fraction as up to nine digits of the shortest decimal
+/// that reads back as the same `f32`, without trailing zeros.
+/// Writes nothing if that rounds to zero.
+pub(crate) fn fmt_fraction(fraction: f32, f: &mut fmt::Formatter) -> fmt::Result {
+    let places = 10u64.pow(PLACES - 9);
+    let mut nanos = ((decimal(fraction) + places / 2) / places).min(999_999_999);
+    if nanos == 0 {
+        return Ok(());
+    }
+
+    let mut digits = 9;
+    loop {
+        let (rest, digit) = (nanos / 10, nanos % 10);
+        if digit > 0 {
+            break;
+        }
+        nanos = rest;
+        digits -= 1;
+    }
+    write!(f, ".{:0digits$}", nanos, digits = digits)
+}
+
+/// Omits a fraction of zero.
+impl<N> fmt::Display for LocalTime<N>
+where
+    N: NaiveTime + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.naive.fmt(f)?;
+        if self.fraction > 0. {
+            fmt_fraction(self.fraction, f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes UTC as `Z`.
+impl<N> fmt::Display for GlobalTime<N>
+where
+    N: NaiveTime + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.local.fmt(f)?;
+        match self.timezone {
+            0 => f.write_str("Z"),
+            timezone => write!(
+                f,
+                "{}{:02}{}{:02}",
+                if timezone < 0 { '-' } else { '+' },
+                timezone.abs() / 60,
+                separator(f),
+                timezone.abs() % 60
+            ),
+        }
+    }
+}
+
+impl<N> fmt::Display for AnyTime<N>
+where
+    N: NaiveTime + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnyTime::Global(time) => time.fmt(f),
+            AnyTime::Local(time) => time.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for ApproxNaiveTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxNaiveTime::HMS(time) => time.fmt(f),
+            ApproxNaiveTime::HM(time) => time.fmt(f),
+            ApproxNaiveTime::H(time) => time.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for ApproxLocalTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxLocalTime::HMS(time) => time.fmt(f),
+            ApproxLocalTime::HM(time) => time.fmt(f),
+            ApproxLocalTime::H(time) => time.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for ApproxGlobalTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxGlobalTime::HMS(time) => time.fmt(f),
+            ApproxGlobalTime::HM(time) => time.fmt(f),
+            ApproxGlobalTime::H(time) => time.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for ApproxAnyTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApproxAnyTime::HMS(time) => time.fmt(f),
+            ApproxAnyTime::HM(time) => time.fmt(f),
+            ApproxAnyTime::H(time) => time.fmt(f),
+        }
+    }
+}
+
 impl Valid for HmsTime {
     /// Accepts leap seconds on any day
     /// since they are not predictable.
+    /// See [`DateTime::is_valid_strict`](crate::DateTime::is_valid_strict).
     fn is_valid(&self) -> bool {
-        HmTime::from(self.clone()).is_valid() && self.second <= 60
+        HmTime::from(self.clone()).is_valid()
+            && self.second <= 60
+            && (self.hour < 24 || self.second == 0)
     }
 }
 
 impl Valid for HmTime {
     fn is_valid(&self) -> bool {
-        HTime::from(self.clone()).is_valid() && self.minute <= 59
+        HTime::from(self.clone()).is_valid()
+            && self.minute <= 59
+            && (self.hour < 24 || self.minute == 0)
     }
 }
 
@@ -160,16 +325,18 @@ impl Valid for HTime {
 
 impl<N> Valid for LocalTime<N>
 where
-    N: NaiveTime + Valid,
+    N: NaiveTime + Valid + EndOfDay,
 {
     fn is_valid(&self) -> bool {
-        self.naive.is_valid() && self.fraction < 1.
+        self.naive.is_valid()
+            && self.fraction < 1.
+            && (self.fraction == 0. || !self.naive.is_end_of_day())
     }
 }
 
 impl<N> Valid for GlobalTime<N>
 where
-    N: NaiveTime + Valid,
+    N: NaiveTime + Valid + EndOfDay,
 {
     fn is_valid(&self) -> bool {
         self.local.is_valid() && self.timezone > -24 * 60 && self.timezone < 24 * 60
@@ -178,7 +345,7 @@ where
 
 impl<N> Valid for AnyTime<N>
 where
-    N: NaiveTime + Valid,
+    N: NaiveTime + Valid + EndOfDay,
 {
     fn is_valid(&self) -> bool {
         match self {
@@ -188,6 +355,141 @@ where
     }
 }
 
+impl Valid for ApproxLocalTime {
+    fn is_valid(&self) -> bool {
+        match self {
+            ApproxLocalTime::HMS(time) => time.is_valid(),
+            ApproxLocalTime::HM(time) => time.is_valid(),
+            ApproxLocalTime::H(time) => time.is_valid(),
+        }
+    }
+}
+
+impl Valid for ApproxGlobalTime {
+    fn is_valid(&self) -> bool {
+        match self {
+            ApproxGlobalTime::HMS(time) => time.is_valid(),
+            ApproxGlobalTime::HM(time) => time.is_valid(),
+            ApproxGlobalTime::H(time) => time.is_valid(),
+        }
+    }
+}
+
+impl Valid for ApproxAnyTime {
+    fn is_valid(&self) -> bool {
+        match self {
+            ApproxAnyTime::HMS(time) => time.is_valid(),
+            ApproxAnyTime::HM(time) => time.is_valid(),
+            ApproxAnyTime::H(time) => time.is_valid(),
+        }
+    }
+}
+
+impl EndOfDay for HmsTime {
+    fn start_of_next_day(&self) -> Option<Self> {
+        if self.hour == 24 && self.minute == 0 && self.second == 0 {
+            Some(Self {
+                hour: 0,
+                ..self.clone()
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl EndOfDay for HmTime {
+    fn start_of_next_day(&self) -> Option<Self> {
+        if self.hour == 24 && self.minute == 0 {
+            Some(Self {
+                hour: 0,
+                ..self.clone()
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl EndOfDay for HTime {
+    fn start_of_next_day(&self) -> Option<Self> {
+        if self.hour == 24 {
+            Some(Self { hour: 0 })
+        } else {
+            None
+        }
+    }
+}
+
+impl<N> EndOfDay for LocalTime<N>
+where
+    N: NaiveTime + EndOfDay,
+{
+    fn start_of_next_day(&self) -> Option<Self> {
+        if self.fraction != 0. {
+            return None;
+        }
+        self.naive.start_of_next_day().map(|naive| Self {
+            naive,
+            fraction: self.fraction,
+        })
+    }
+}
+
+impl<N> EndOfDay for GlobalTime<N>
+where
+    N: NaiveTime + EndOfDay,
+{
+    fn start_of_next_day(&self) -> Option<Self> {
+        self.local.start_of_next_day().map(|local| Self {
+            local,
+            timezone: self.timezone,
+        })
+    }
+}
+
+impl<N> EndOfDay for AnyTime<N>
+where
+    N: NaiveTime + EndOfDay,
+{
+    fn start_of_next_day(&self) -> Option<Self> {
+        match self {
+            AnyTime::Global(time) => time.start_of_next_day().map(AnyTime::Global),
+            AnyTime::Local(time) => time.start_of_next_day().map(AnyTime::Local),
+        }
+    }
+}
+
+impl EndOfDay for ApproxLocalTime {
+    fn start_of_next_day(&self) -> Option<Self> {
+        match self {
+            ApproxLocalTime::HMS(time) => time.start_of_next_day().map(ApproxLocalTime::HMS),
+            ApproxLocalTime::HM(time) => time.start_of_next_day().map(ApproxLocalTime::HM),
+            ApproxLocalTime::H(time) => time.start_of_next_day().map(ApproxLocalTime::H),
+        }
+    }
+}
+
+impl EndOfDay for ApproxGlobalTime {
+    fn start_of_next_day(&self) -> Option<Self> {
+        match self {
+            ApproxGlobalTime::HMS(time) => time.start_of_next_day().map(ApproxGlobalTime::HMS),
+            ApproxGlobalTime::HM(time) => time.start_of_next_day().map(ApproxGlobalTime::HM),
+            ApproxGlobalTime::H(time) => time.start_of_next_day().map(ApproxGlobalTime::H),
+        }
+    }
+}
+
+impl EndOfDay for ApproxAnyTime {
+    fn start_of_next_day(&self) -> Option<Self> {
+        match self {
+            ApproxAnyTime::HMS(time) => time.start_of_next_day().map(ApproxAnyTime::HMS),
+            ApproxAnyTime::HM(time) => time.start_of_next_day().map(ApproxAnyTime::HM),
+            ApproxAnyTime::H(time) => time.start_of_next_day().map(ApproxAnyTime::H),
+        }
+    }
+}
+
 impl From<HmsTime> for HmTime {
     fn from(t: HmsTime) -> Self {
         Self {
@@ -309,9 +611,159 @@ impl From<AnyTime<HmTime>> for AnyTime<HTime> {
     }
 }
 
+/// Places of a fraction kept by [`decimal`], far below a nanosecond of an hour.
+const PLACES: u32 = 15;
+
+/// A fraction below one in units of `10^-PLACES`, read from the shortest decimal
+/// that converts back to the same `f32`. For fractions parsed from
+/// up to seven digits, that is the decimal that was parsed,
+/// without the error of the binary float.
+fn decimal(fraction: f32) -> u64 {
+    struct Digits {
+        value: u64,
+        places: Option<u32>,
+    }
+
+    impl fmt::Write for Digits {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            for b in s.bytes() {
+                match (b, self.places) {
+                    (b'.', _) => self.places = Some(0),
+                    (b'0'..=b'9', Some(places)) if places < PLACES => {
+                        self.value = self.value * 10 + u64::from(b - b'0');
+                        self.places = Some(places + 1);
+                    }
+                    // One or more.
+                    (b'1'..=b'9', None) => return Err(fmt::Error),
+                    _ => {}
+                }
+            }
+            Ok(())
+        }
+    }
+
+    let mut digits = Digits {
+        value: 0,
+        places: None,
+    };
+    match fmt::write(&mut digits, format_args!("{}", fraction)) {
+        Ok(()) => digits.value * 10u64.pow(PLACES - digits.places.unwrap_or(0)),
+        Err(_) => 10u64.pow(PLACES) - 1,
+    }
+}
+
+/// Whole nanoseconds in a fraction of a unit of the given seconds,
+/// rounded to the nearest and kept below the whole unit.
+fn nanoseconds(fraction: f32, seconds: u64) -> u64 {
+    let places = 10u128.pow(PLACES);
+    let nanos =
+        (u128::from(decimal(fraction)) * u128::from(seconds) * 1_000_000_000 + places / 2) / places;
+    (nanos as u64).min(seconds * 1_000_000_000 - 1)
+}
+
+/// Spreads the decimal fraction of a minute over seconds,
+/// rounded to whole nanoseconds.
+impl From<LocalTime<HmTime>> for LocalTime<HmsTime> {
+    fn from(t: LocalTime<HmTime>) -> Self {
+        let nanos = nanoseconds(t.fraction, 60);
+        Self {
+            naive: HmsTime {
+                hour: t.naive.hour,
+                minute: t.naive.minute,
+                second: (nanos / 1_000_000_000) as u8,
+            },
+            fraction: crate::datetime::fraction((nanos % 1_000_000_000) as u32),
+        }
+    }
+}
+
+/// Spreads the decimal fraction of an hour over minutes and seconds,
+/// rounded to whole nanoseconds.
+impl From<LocalTime<HTime>> for LocalTime<HmsTime> {
+    fn from(t: LocalTime<HTime>) -> Self {
+        let nanos = nanoseconds(t.fraction, 60 * 60);
+        let seconds = nanos / 1_000_000_000;
+        Self {
+            naive: HmsTime {
+                hour: t.naive.hour,
+                minute: (seconds / 60) as u8,
+                second: (seconds % 60) as u8,
+            },
+            fraction: crate::datetime::fraction((nanos % 1_000_000_000) as u32),
+        }
+    }
+}
+
+impl From<GlobalTime<HmTime>> for GlobalTime<HmsTime> {
+    fn from(t: GlobalTime<HmTime>) -> Self {
+        Self {
+            local: t.local.into(),
+            timezone: t.timezone,
+        }
+    }
+}
+
+impl From<GlobalTime<HTime>> for GlobalTime<HmsTime> {
+    fn from(t: GlobalTime<HTime>) -> Self {
+        Self {
+            local: t.local.into(),
+            timezone: t.timezone,
+        }
+    }
+}
+
+impl From<AnyTime<HmTime>> for AnyTime<HmsTime> {
+    fn from(t: AnyTime<HmTime>) -> Self {
+        match t {
+            AnyTime::Global(t) => AnyTime::Global(t.into()),
+            AnyTime::Local(t) => AnyTime::Local(t.into()),
+        }
+    }
+}
+
+impl From<AnyTime<HTime>> for AnyTime<HmsTime> {
+    fn from(t: AnyTime<HTime>) -> Self {
+        match t {
+            AnyTime::Global(t) => AnyTime::Global(t.into()),
+            AnyTime::Local(t) => AnyTime::Local(t.into()),
+        }
+    }
+}
+
+impl From<ApproxLocalTime> for LocalTime<HmsTime> {
+    fn from(t: ApproxLocalTime) -> Self {
+        match t {
+            ApproxLocalTime::HMS(t) => t,
+            ApproxLocalTime::HM(t) => t.into(),
+            ApproxLocalTime::H(t) => t.into(),
+        }
+    }
+}
+
+impl From<ApproxGlobalTime> for GlobalTime<HmsTime> {
+    fn from(t: ApproxGlobalTime) -> Self {
+        match t {
+            ApproxGlobalTime::HMS(t) => t,
+            ApproxGlobalTime::HM(t) => t.into(),
+            ApproxGlobalTime::H(t) => t.into(),
+        }
+    }
+}
+
+impl From<ApproxAnyTime> for AnyTime<HmsTime> {
+    fn from(t: ApproxAnyTime) -> Self {
+        match t {
+            ApproxAnyTime::HMS(t) => t,
+            ApproxAnyTime::HM(t) => t.into(),
+            ApproxAnyTime::H(t) => t.into(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
 
     #[test]
     fn valid_time_hms() {
@@ -352,6 +804,224 @@ mod tests {
         assert!(!HTime { hour: 25 }.is_valid());
     }
 
+    #[test]
+    fn valid_time_end_of_day() {
+        assert!(HmTime {
+            hour: 24,
+            minute: 0
+        }
+        .is_valid());
+        assert!(!HmTime {
+            hour: 24,
+            minute: 1
+        }
+        .is_valid());
+
+        assert!(HmsTime {
+            hour: 24,
+            minute: 0,
+            second: 0
+        }
+        .is_valid());
+        assert!(!HmsTime {
+            hour: 24,
+            minute: 0,
+            second: 1
+        }
+        .is_valid());
+        assert!(!HmsTime {
+            hour: 24,
+            minute: 0,
+            second: 60
+        }
+        .is_valid());
+
+        assert!(!LocalTime {
+            naive: HTime { hour: 24 },
+            fraction: 0.5
+        }
+        .is_valid());
+    }
+
+    #[test]
+    fn start_of_next_day() {
+        assert_eq!(
+            HmsTime {
+                hour: 24,
+                minute: 0,
+                second: 0
+            }
+            .start_of_next_day(),
+            Some(HmsTime {
+                hour: 0,
+                minute: 0,
+                second: 0
+            })
+        );
+        assert_eq!(
+            HmsTime {
+                hour: 23,
+                minute: 59,
+                second: 59
+            }
+            .start_of_next_day(),
+            None
+        );
+        assert_eq!(
+            LocalTime {
+                naive: HmTime {
+                    hour: 24,
+                    minute: 0
+                },
+                fraction: 0.1
+            }
+            .start_of_next_day(),
+            None
+        );
+        assert_eq!(
+            ApproxAnyTime::H(AnyTime::Global(GlobalTime {
+                local: LocalTime {
+                    naive: HTime { hour: 24 },
+                    fraction: 0.
+                },
+                timezone: 60
+            }))
+            .start_of_next_day(),
+            Some(ApproxAnyTime::H(AnyTime::Global(GlobalTime {
+                local: LocalTime {
+                    naive: HTime { hour: 0 },
+                    fraction: 0.
+                },
+                timezone: 60
+            })))
+        );
+    }
+
+    #[test]
+    fn local_hms_from_hm() {
+        assert_eq!(
+            LocalTime::<HmsTime>::from(LocalTime {
+                naive: HmTime {
+                    hour: 12,
+                    minute: 30
+                },
+                fraction: 0.5
+            }),
+            LocalTime {
+                naive: HmsTime {
+                    hour: 12,
+                    minute: 30,
+                    second: 30
+                },
+                fraction: 0.
+            }
+        );
+        assert_eq!(
+            LocalTime::<HmsTime>::from(LocalTime {
+                naive: HmTime {
+                    hour: 12,
+                    minute: 30
+                },
+                fraction: 0.1
+            }),
+            LocalTime {
+                naive: HmsTime {
+                    hour: 12,
+                    minute: 30,
+                    second: 6
+                },
+                fraction: 0.
+            }
+        );
+    }
+
+    #[test]
+    fn local_hms_from_h() {
+        assert_eq!(
+            LocalTime::<HmsTime>::from(LocalTime {
+                naive: HTime { hour: 12 },
+                fraction: 0.75
+            }),
+            LocalTime {
+                naive: HmsTime {
+                    hour: 12,
+                    minute: 45,
+                    second: 0
+                },
+                fraction: 0.
+            }
+        );
+        assert_eq!(
+            LocalTime::<HmsTime>::from(LocalTime {
+                naive: HTime { hour: 12 },
+                fraction: 0.515625
+            }),
+            LocalTime {
+                naive: HmsTime {
+                    hour: 12,
+                    minute: 30,
+                    second: 56
+                },
+                fraction: 0.25
+            }
+        );
+        assert_eq!(
+            LocalTime::<HmsTime>::from(LocalTime {
+                naive: HTime { hour: 12 },
+                fraction: 0.1
+            }),
+            LocalTime {
+                naive: HmsTime {
+                    hour: 12,
+                    minute: 6,
+                    second: 0
+                },
+                fraction: 0.
+            }
+        );
+        assert_eq!(
+            LocalTime::<HmsTime>::from(LocalTime {
+                naive: HTime { hour: 12 },
+                fraction: 0.0000001
+            }),
+            LocalTime {
+                naive: HmsTime {
+                    hour: 12,
+                    minute: 0,
+                    second: 0
+                },
+                fraction: 0.00036
+            }
+        );
+        let end = LocalTime::<HmsTime>::from(LocalTime {
+            naive: HTime { hour: 12 },
+            fraction: 1.,
+        });
+        assert_eq!(
+            end,
+            LocalTime {
+                naive: HmsTime {
+                    hour: 12,
+                    minute: 59,
+                    second: 59
+                },
+                fraction: 1. - f32::EPSILON / 2.
+            }
+        );
+        assert!(end.is_valid());
+
+        let end = LocalTime::<HmsTime>::from(LocalTime {
+            naive: HmTime {
+                hour: 12,
+                minute: 30,
+            },
+            fraction: 1.,
+        });
+        assert_eq!(end.naive.second, 59);
+        assert_eq!(end.fraction, 1. - f32::EPSILON / 2.);
+        assert!(end.is_valid());
+    }
+
     #[test]
     fn valid_time_local() {
         assert!(LocalTime {
@@ -414,4 +1084,62 @@ mod tests {
         assert!(!AnyTime::Local(local.clone()).is_valid());
         assert!(!AnyTime::Global(GlobalTime { local, timezone: 0 }).is_valid());
     }
+
+    #[test]
+    fn display() {
+        for time in &[
+            "13:42:02",
+            "13:42:02.5",
+            "13:42:02.05",
+            "13:42:02.000001",
+            "13:42",
+            "13:42.25",
+            "13",
+            "13.5",
+        ] {
+            assert_eq!(ApproxLocalTime::from_str(time).unwrap().to_string(), *time);
+        }
+        for time in &["13:42:02Z", "13:42:02.5+02:00", "13:42-05:30", "13+01:00"] {
+            assert_eq!(ApproxAnyTime::from_str(time).unwrap().to_string(), *time);
+        }
+        assert_eq!(HmsTime::from_str("134202").unwrap().to_string(), "13:42:02");
+        for (fraction, written) in &[
+            (1e-9, "13:42:02.000000001"),
+            (1e-12, "13:42:02"),
+            (0.9999999, "13:42:02.9999999"),
+            (1., "13:42:02.999999999"),
+        ] {
+            let time = LocalTime {
+                naive: HmsTime {
+                    hour: 13,
+                    minute: 42,
+                    second: 2,
+                },
+                fraction: *fraction,
+            };
+            assert_eq!(time.to_string(), *written);
+        }
+        assert_eq!(
+            "13:42:02.123456789"
+                .parse::<LocalTime<HmsTime>>()
+                .unwrap()
+                .to_string(),
+            "13:42:02.12345679"
+        );
+        assert_eq!(
+            "13:42:02+0200".parse::<GlobalTime>().unwrap().to_string(),
+            "13:42:02+02:00"
+        );
+
+        for (extended, basic) in &[
+            ("13:42:02.5Z", "134202.5Z"),
+            ("13:42-05:30", "1342-0530"),
+            ("13+01:00", "13+0100"),
+        ] {
+            assert_eq!(
+                format!("{:#}", ApproxAnyTime::from_str(extended).unwrap()),
+                *basic
+            );
+        }
+    }
 }
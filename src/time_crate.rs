@@ -0,0 +1,382 @@
+#![cfg(feature = "time")]
+
+use crate::{EndOfDay, Valid};
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+/// Reasons a conversion between this crate and the `time` crate can fail.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Error {
+    /// The date does not exist or is out of range.
+    InvalidDate,
+    /// The time of day does not exist
+    /// or is a leap second, which the `time` crate does not support.
+    InvalidTime,
+    /// The difference from UTC is out of range.
+    InvalidOffset,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidDate => "invalid or out-of-range date",
+            Error::InvalidTime => "invalid or unsupported time of day",
+            Error::InvalidOffset => "out-of-range difference from UTC",
+        })
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn weekday(day: u8) -> Result<Weekday, Error> {
+    Ok(match day {
+        1 => Weekday::Monday,
+        2 => Weekday::Tuesday,
+        3 => Weekday::Wednesday,
+        4 => Weekday::Thursday,
+        5 => Weekday::Friday,
+        6 => Weekday::Saturday,
+        7 => Weekday::Sunday,
+        _ => return Err(Error::InvalidDate),
+    })
+}
+
+fn year(year: i32) -> Result<i16, Error> {
+    i16::try_from(year).or(Err(Error::InvalidDate))
+}
+
+impl TryFrom<crate::YmdDate> for Date {
+    type Error = Error;
+
+    fn try_from(date: crate::YmdDate) -> Result<Self, Self::Error> {
+        let month = Month::try_from(date.month).or(Err(Error::InvalidDate))?;
+        Date::from_calendar_date(date.year.into(), month, date.day).or(Err(Error::InvalidDate))
+    }
+}
+
+impl TryFrom<crate::WdDate> for Date {
+    type Error = Error;
+
+    fn try_from(date: crate::WdDate) -> Result<Self, Self::Error> {
+        Date::from_iso_week_date(date.year.into(), date.week, weekday(date.day)?)
+            .or(Err(Error::InvalidDate))
+    }
+}
+
+impl TryFrom<crate::ODate> for Date {
+    type Error = Error;
+
+    fn try_from(date: crate::ODate) -> Result<Self, Self::Error> {
+        Date::from_ordinal_date(date.year.into(), date.day).or(Err(Error::InvalidDate))
+    }
+}
+
+impl TryFrom<crate::Date> for Date {
+    type Error = Error;
+
+    fn try_from(date: crate::Date) -> Result<Self, Self::Error> {
+        match date {
+            crate::Date::YMD(date) => date.try_into(),
+            crate::Date::WD(date) => date.try_into(),
+            crate::Date::O(date) => date.try_into(),
+        }
+    }
+}
+
+impl TryFrom<Date> for crate::YmdDate {
+    type Error = Error;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: year(date.year())?,
+            month: date.month().into(),
+            day: date.day(),
+        })
+    }
+}
+
+impl TryFrom<Date> for crate::WdDate {
+    type Error = Error;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        let (year, week, weekday) = date.to_iso_week_date();
+        Ok(Self {
+            year: self::year(year)?,
+            week,
+            day: weekday.number_from_monday(),
+        })
+    }
+}
+
+impl TryFrom<Date> for crate::ODate {
+    type Error = Error;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: year(date.year())?,
+            day: date.ordinal(),
+        })
+    }
+}
+
+/// Calendar date representation.
+impl TryFrom<Date> for crate::Date {
+    type Error = Error;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        date.try_into().map(crate::Date::YMD)
+    }
+}
+
+/// Fails on leap seconds and the end of a day (`24:00`).
+impl TryFrom<crate::LocalTime> for Time {
+    type Error = Error;
+
+    fn try_from(time: crate::LocalTime) -> Result<Self, Self::Error> {
+        if !time.is_valid() {
+            return Err(Error::InvalidTime);
+        }
+
+        Time::from_hms_nano(
+            time.naive.hour,
+            time.naive.minute,
+            time.naive.second,
+            time.nanosecond(),
+        )
+        .or(Err(Error::InvalidTime))
+    }
+}
+
+impl TryFrom<crate::HmsTime> for Time {
+    type Error = Error;
+
+    fn try_from(time: crate::HmsTime) -> Result<Self, Self::Error> {
+        crate::LocalTime {
+            naive: time,
+            fraction: 0.,
+        }
+        .try_into()
+    }
+}
+
+impl From<Time> for crate::LocalTime {
+    fn from(time: Time) -> Self {
+        Self {
+            naive: time.into(),
+            fraction: crate::datetime::fraction(time.nanosecond()),
+        }
+    }
+}
+
+/// Drops fractions of a second.
+impl From<Time> for crate::HmsTime {
+    fn from(time: Time) -> Self {
+        Self {
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+        }
+    }
+}
+
+impl<N> TryFrom<crate::GlobalTime<N>> for UtcOffset
+where
+    N: crate::NaiveTime,
+{
+    type Error = Error;
+
+    fn try_from(time: crate::GlobalTime<N>) -> Result<Self, Self::Error> {
+        UtcOffset::from_whole_seconds(i32::from(time.timezone) * 60).or(Err(Error::InvalidOffset))
+    }
+}
+
+impl TryFrom<crate::GlobalTime> for (Time, UtcOffset) {
+    type Error = Error;
+
+    fn try_from(time: crate::GlobalTime) -> Result<Self, Self::Error> {
+        let offset = UtcOffset::try_from(time.clone())?;
+        Ok((time.local.try_into()?, offset))
+    }
+}
+
+/// Fails if the offset is not a whole number of minutes.
+impl TryFrom<(Time, UtcOffset)> for crate::GlobalTime {
+    type Error = Error;
+
+    fn try_from((time, offset): (Time, UtcOffset)) -> Result<Self, Self::Error> {
+        let seconds = offset.whole_seconds();
+        if seconds % 60 != 0 {
+            return Err(Error::InvalidOffset);
+        }
+        Ok(Self {
+            local: time.into(),
+            timezone: (seconds / 60) as i16,
+        })
+    }
+}
+
+/// Maps the end of a day (`24:00`) to the beginning of the next one.
+impl TryFrom<crate::DateTime<crate::Date, crate::LocalTime>> for PrimitiveDateTime {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::LocalTime>) -> Result<Self, Self::Error> {
+        let date = Date::try_from(dt.date)?;
+        Ok(match dt.time.start_of_next_day() {
+            Some(time) => {
+                PrimitiveDateTime::new(date.next_day().ok_or(Error::InvalidDate)?, time.try_into()?)
+            }
+            None => PrimitiveDateTime::new(date, dt.time.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<crate::DateTime<crate::Date, crate::GlobalTime>> for OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(dt: crate::DateTime<crate::Date, crate::GlobalTime>) -> Result<Self, Self::Error> {
+        let offset = UtcOffset::try_from(dt.time.clone())?;
+        Ok(PrimitiveDateTime::try_from(crate::DateTime {
+            date: dt.date,
+            time: dt.time.local,
+        })?
+        .assume_offset(offset))
+    }
+}
+
+macro_rules! impl_try_from_time {
+    ($($date:ty),*) => {
+        $(
+            impl TryFrom<PrimitiveDateTime> for crate::DateTime<$date, crate::LocalTime> {
+                type Error = Error;
+
+                fn try_from(dt: PrimitiveDateTime) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        date: dt.date().try_into()?,
+                        time: dt.time().into(),
+                    })
+                }
+            }
+
+            /// Keeps the local time and offset of the `time` value.
+            /// Fails if the offset is not a whole number of minutes.
+            impl TryFrom<OffsetDateTime> for crate::DateTime<$date, crate::GlobalTime> {
+                type Error = Error;
+
+                fn try_from(dt: OffsetDateTime) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        date: dt.date().try_into()?,
+                        time: (dt.time(), dt.offset()).try_into()?,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_time!(crate::Date, crate::YmdDate, crate::WdDate, crate::ODate);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn date() {
+        let expected = Date::from_calendar_date(2018, Month::August, 2).unwrap();
+        for date in &["2018-08-02", "2018-W31-4", "2018-214"] {
+            let date = crate::Date::from_str(date).unwrap();
+            assert_eq!(Date::try_from(date), Ok(expected));
+        }
+
+        assert_eq!(
+            crate::WdDate::try_from(Date::from_calendar_date(2021, Month::January, 3).unwrap()),
+            Ok(crate::WdDate {
+                year: 2020,
+                week: 53,
+                day: 7,
+            })
+        );
+        assert_eq!(
+            crate::ODate::try_from(expected),
+            Ok(crate::ODate {
+                year: 2018,
+                day: 214,
+            })
+        );
+        assert_eq!(
+            Date::try_from(crate::YmdDate {
+                year: 10_000,
+                month: 1,
+                day: 1,
+            }),
+            Err(Error::InvalidDate)
+        );
+        assert_eq!(
+            Date::try_from(crate::Date::from_str("2018-02-30").unwrap()),
+            Err(Error::InvalidDate)
+        );
+    }
+
+    #[test]
+    fn time() {
+        let time = crate::LocalTime::from_str("13:42:02.5").unwrap();
+        let expected = Time::from_hms_milli(13, 42, 2, 500).unwrap();
+        assert_eq!(Time::try_from(time.clone()), Ok(expected));
+        assert_eq!(crate::LocalTime::from(expected), time);
+
+        let end = Time::from_hms_nano(13, 42, 2, 999_999_999).unwrap();
+        let time = crate::LocalTime::from(end);
+        assert!(time.is_valid());
+        // An `f32` only keeps about seven digits of the fraction.
+        let back = Time::try_from(time).unwrap();
+        assert_eq!(back.second(), 2);
+        assert!(back.nanosecond() > 999_999_900);
+
+        assert_eq!(
+            Time::try_from(crate::LocalTime::from_str("23:59:60").unwrap()),
+            Err(Error::InvalidTime)
+        );
+    }
+
+    #[test]
+    fn offset() {
+        let time = "13:42:02+05:30".parse::<crate::GlobalTime>().unwrap();
+        let offset = UtcOffset::from_hms(5, 30, 0).unwrap();
+        let local = Time::from_hms(13, 42, 2).unwrap();
+        assert_eq!(UtcOffset::try_from(time.clone()), Ok(offset));
+        assert_eq!(crate::GlobalTime::try_from((local, offset)), Ok(time));
+        assert_eq!(
+            crate::GlobalTime::try_from((local, UtcOffset::from_hms(0, 0, 30).unwrap())),
+            Err(Error::InvalidOffset)
+        );
+    }
+
+    #[test]
+    fn datetime() {
+        let dt = crate::DateTime::<crate::Date, crate::GlobalTime>::from_str(
+            "2018-08-02T13:42:02.5+02:00",
+        )
+        .unwrap();
+        let expected = PrimitiveDateTime::new(
+            Date::from_calendar_date(2018, Month::August, 2).unwrap(),
+            Time::from_hms_milli(13, 42, 2, 500).unwrap(),
+        )
+        .assume_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+        assert_eq!(OffsetDateTime::try_from(dt.clone()), Ok(expected));
+        assert_eq!(crate::DateTime::try_from(expected), Ok(dt));
+
+        assert_eq!(
+            PrimitiveDateTime::try_from(
+                crate::DateTime::<crate::Date, crate::LocalTime>::from_str("2018-12-31T24:00:00")
+                    .unwrap()
+            ),
+            Ok(PrimitiveDateTime::new(
+                Date::from_calendar_date(2019, Month::January, 1).unwrap(),
+                Time::MIDNIGHT,
+            ))
+        );
+    }
+}
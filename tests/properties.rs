@@ -0,0 +1,146 @@
+use iso_8601::*;
+use proptest::prelude::*;
+use std::{fmt::Display, str::FromStr};
+
+macro_rules! parse_all {
+    ($input:expr, $($ty:ty),* $(,)?) => {
+        $(let _ = <$ty>::from_str($input);)*
+    };
+}
+
+fn parse_everything(input: &str) {
+    parse_all!(
+        input,
+        Date,
+        ApproxDate,
+        YmdDate,
+        YmDate,
+        YDate,
+        CDate,
+        WdDate,
+        WDate,
+        ODate,
+        HmsTime,
+        HmTime,
+        HTime,
+        ApproxNaiveTime,
+        LocalTime,
+        GlobalTime,
+        AnyTime,
+        ApproxLocalTime,
+        ApproxGlobalTime,
+        ApproxAnyTime,
+        DateTime<Date, GlobalTime>,
+        DateTime<Date, LocalTime<HmTime>>,
+        DateTime<Date, AnyTime<HTime>>,
+        DateTime<Date, ApproxAnyTime>,
+        DateTime<ApproxDate, GlobalTime<HmTime>>,
+        DateTime<ApproxDate, LocalTime<HTime>>,
+        DateTime<ApproxDate, AnyTime>,
+        DateTime<ApproxDate, ApproxAnyTime>,
+        PartialDateTime<ApproxDate, ApproxAnyTime>,
+    );
+    for (range, _) in find_iter(input) {
+        let _ = &input[range];
+    }
+}
+
+fn year() -> impl Strategy<Value = i16> {
+    -9999..=9999i16
+}
+
+fn date() -> impl Strategy<Value = Date> {
+    prop_oneof![
+        (year(), 0..100u8, 0..100u8).prop_map(|(year, month, day)| Date::YMD(YmdDate {
+            year,
+            month,
+            day
+        })),
+        (year(), 0..100u8, 0..10u8).prop_map(|(year, week, day)| Date::WD(WdDate {
+            year,
+            week,
+            day
+        })),
+        (year(), 0..1000u16).prop_map(|(year, day)| Date::O(ODate { year, day })),
+    ]
+}
+
+fn local_time() -> impl Strategy<Value = LocalTime> {
+    (0..100u8, 0..100u8, 0..100u8, 0..1_000_000_000u32).prop_map(|(hour, minute, second, nanos)| {
+        LocalTime {
+            naive: HmsTime {
+                hour,
+                minute,
+                second,
+            },
+            // Rounded to `f32`, the largest nanoseconds would give one.
+            fraction: (nanos as f32 / 1_000_000_000.).min(1. - f32::EPSILON / 2.),
+        }
+    })
+}
+
+fn global_time() -> impl Strategy<Value = GlobalTime> {
+    (local_time(), -5999..=5999i16).prop_map(|(local, timezone)| GlobalTime { local, timezone })
+}
+
+fn round_trip<T>(value: &T) -> Result<(), TestCaseError>
+where
+    T: FromStr + Display + PartialEq + std::fmt::Debug,
+{
+    for formatted in &[format!("{}", value), format!("{:#}", value)] {
+        let parsed = T::from_str(formatted).ok();
+        prop_assert_eq!(parsed.as_ref(), Some(value), "{}", formatted);
+    }
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn no_panic_on_arbitrary_input(input in "\\PC{0,40}") {
+        parse_everything(&input);
+    }
+
+    #[test]
+    fn no_panic_on_date_like_input(input in "[-+0-9TWZ:.,\u{2212}\u{2010} ]{0,40}") {
+        parse_everything(&input);
+    }
+
+    #[test]
+    fn no_panic_on_iso_8601_shaped_input(
+        input in "[-+]?[0-9]{2,4}(-?W?[0-9]{1,3}){0,2}(T[0-9]{2}(:?[0-9]{2}){0,2}([.,][0-9]{1,4})?(Z|[-+][0-9]{2}(:?[0-9]{2})?)?)?"
+    ) {
+        parse_everything(&input);
+    }
+
+    #[test]
+    fn date_round_trip(date in date()) {
+        round_trip(&date)?;
+    }
+
+    #[test]
+    fn local_time_round_trip(time in local_time()) {
+        round_trip(&time)?;
+    }
+
+    #[test]
+    fn global_time_round_trip(time in global_time()) {
+        round_trip(&time)?;
+    }
+
+    #[test]
+    fn datetime_round_trip(date in date(), time in global_time()) {
+        round_trip(&DateTime { date: date.clone(), time: time.clone() })?;
+        round_trip(&DateTime { date, time: time.local })?;
+    }
+
+    #[test]
+    fn layout_reproduces_input(
+        input in "([-+\u{2212}]?[0-9]{4}(-?W?[0-9]{1,3}){0,2})?(T[0-9]{2}(:?[0-9]{2}){0,2}(\\.[0-9]{1,19})?(Z|[-+\u{2212}][0-9]{2}(:?[0-5][0-9])?)?)?"
+    ) {
+        use iso_8601::input::Parse;
+
+        if let Ok((rest, parsed)) = layout::Parsed::<PartialDateTime>::parse_complete(input.as_str()) {
+            prop_assert_eq!(parsed.to_string(), &input[..input.len() - rest.len()]);
+        }
+    }
+}